@@ -0,0 +1,320 @@
+use anyhow::{Context, Result};
+use std::collections::VecDeque;
+use std::ops::Range;
+use std::path::Path;
+use tauri::{AppHandle, Window};
+use tokio_util::sync::CancellationToken;
+
+use crate::ffmpeg;
+use crate::logger;
+use crate::subtitle::SubtitleDocument;
+
+/// Chunks are planned at this share of the size limit, leaving room for container overhead
+const SIZE_HEADROOM: f64 = 0.9;
+/// How far before the hard limit a cut may move back to land in a pause
+const MAX_SILENCE_LOOKBACK_SECS: f64 = 120.0;
+/// A chunk still over the limit is not split below this length
+const MIN_CHUNK_SECS: f64 = 1.0;
+
+/// One piece of a split audio file
+#[derive(Debug, Clone)]
+pub struct AudioChunk {
+    pub path: String,
+    /// Start of the chunk within the original audio
+    pub offset_ms: u64,
+}
+
+/// Split `0..duration_secs` into ranges of at most `max_chunk_secs`.
+/// Each cut is placed in the middle of the latest pause within MAX_SILENCE_LOOKBACK_SECS
+/// of the limit, so words aren't cut in half; without one it falls on the limit itself.
+pub fn plan_chunks(
+    duration_secs: f64,
+    max_chunk_secs: f64,
+    silences: &[(f64, f64)],
+) -> Vec<Range<f64>> {
+    let mut chunks = Vec::new();
+    let mut start = 0.0;
+
+    while duration_secs - start > max_chunk_secs {
+        let limit = start + max_chunk_secs;
+        let earliest = (limit - MAX_SILENCE_LOOKBACK_SECS).max(start);
+        let cut = silences
+            .iter()
+            .map(|(silence_start, silence_end)| (silence_start + silence_end) / 2.0)
+            .filter(|middle| *middle > earliest && *middle <= limit)
+            .fold(None, |latest: Option<f64>, middle| {
+                Some(latest.map_or(middle, |latest| latest.max(middle)))
+            })
+            .unwrap_or(limit);
+
+        chunks.push(start..cut);
+        start = cut;
+    }
+    chunks.push(start..duration_secs);
+
+    chunks
+}
+
+/// Split an audio file that exceeds `max_bytes` into chunk files that don't,
+/// cutting at pauses where possible. Chunk files are written next to the original.
+/// Chunks are planned from the average bitrate, so a variable bitrate chunk that
+/// still ends up too big is split again.
+pub async fn split_audio(
+    audio_path: &str,
+    max_bytes: u64,
+    task_id: &str,
+    window: &Window,
+    app_handle: &AppHandle,
+    cancel_token: &CancellationToken,
+) -> Result<Vec<AudioChunk>> {
+    let file_size = tokio::fs::metadata(audio_path)
+        .await
+        .context("Failed to read audio file metadata")?
+        .len();
+    let duration_secs = ffmpeg::probe_media(app_handle, audio_path)
+        .await
+        .context("Failed to probe audio file")?
+        .duration_secs
+        .context("Audio duration unknown, cannot split the file")?;
+
+    let max_chunk_secs = duration_secs * (max_bytes as f64 / file_size as f64) * SIZE_HEADROOM;
+    let silences =
+        ffmpeg::detect_silences(app_handle, audio_path, duration_secs, cancel_token).await?;
+    let ranges = plan_chunks(duration_secs, max_chunk_secs, &silences);
+
+    logger::append_log_entry(
+        app_handle,
+        window,
+        task_id,
+        "metadata",
+        &format!(
+            "Audio file exceeds the upload limit ({:.1} MB), splitting {:.0}s into {} chunks ({} pauses found)",
+            file_size as f64 / (1024.0 * 1024.0),
+            duration_secs,
+            ranges.len(),
+            silences.len()
+        ),
+    )
+    .await?;
+
+    let audio = Path::new(audio_path);
+    let stem = audio
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .context("Invalid audio file name")?;
+    let extension = audio
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .unwrap_or("wav");
+    // Chunks are re-encoded in the file's codec, at its average bitrate for Opus
+    let bitrate_kbps = (file_size as f64 * 8.0 / duration_secs / 1000.0).round() as u32;
+    let codec = ffmpeg::AudioCodec::of_file(audio, bitrate_kbps);
+
+    let mut pending: VecDeque<Range<f64>> = ranges.into_iter().collect();
+    let mut chunks: Vec<AudioChunk> = Vec::new();
+    while let Some(range) = pending.pop_front() {
+        let chunk_path =
+            audio.with_file_name(format!("{}_part{}.{}", stem, chunks.len() + 1, extension));
+        let chunk_path = chunk_path
+            .to_str()
+            .context("Invalid audio chunk path")?
+            .to_string();
+
+        let result = cut_chunk(
+            audio_path,
+            &range,
+            codec,
+            &chunk_path,
+            task_id,
+            window,
+            app_handle,
+            cancel_token,
+        )
+        .await;
+        let chunk_size = match result {
+            Ok(chunk_size) => chunk_size,
+            Err(e) => {
+                remove_chunks(&chunks).await;
+                return Err(e);
+            }
+        };
+
+        if chunk_size > max_bytes {
+            let _ = tokio::fs::remove_file(&chunk_path).await;
+            let span = range.end - range.start;
+            let max_chunk_secs = span * (max_bytes as f64 / chunk_size as f64) * SIZE_HEADROOM;
+            if max_chunk_secs < MIN_CHUNK_SECS {
+                remove_chunks(&chunks).await;
+                anyhow::bail!(
+                    "Audio chunk at {:.0}s cannot be split below the upload limit",
+                    range.start
+                );
+            }
+
+            logger::append_log_entry(
+                app_handle,
+                window,
+                task_id,
+                "metadata",
+                &format!(
+                    "Audio chunk at {:.0}s is {:.1} MB, over the upload limit; splitting it again",
+                    range.start,
+                    chunk_size as f64 / (1024.0 * 1024.0)
+                ),
+            )
+            .await?;
+
+            let chunk_silences: Vec<(f64, f64)> = silences
+                .iter()
+                .map(|(start, end)| (start - range.start, end - range.start))
+                .collect();
+            for part in plan_chunks(span, max_chunk_secs, &chunk_silences)
+                .into_iter()
+                .rev()
+            {
+                pending.push_front(range.start + part.start..range.start + part.end);
+            }
+            continue;
+        }
+
+        chunks.push(AudioChunk {
+            path: chunk_path,
+            offset_ms: (range.start * 1000.0).round() as u64,
+        });
+    }
+
+    Ok(chunks)
+}
+
+/// Cut one chunk file; returns its size
+#[allow(clippy::too_many_arguments)]
+async fn cut_chunk(
+    audio_path: &str,
+    range: &Range<f64>,
+    codec: ffmpeg::AudioCodec,
+    chunk_path: &str,
+    task_id: &str,
+    window: &Window,
+    app_handle: &AppHandle,
+    cancel_token: &CancellationToken,
+) -> Result<u64> {
+    ffmpeg::cut_audio(
+        audio_path,
+        range.start,
+        range.end - range.start,
+        codec,
+        chunk_path,
+        task_id,
+        window,
+        app_handle,
+        cancel_token,
+    )
+    .await?;
+
+    Ok(tokio::fs::metadata(chunk_path)
+        .await
+        .context("Failed to read audio chunk metadata")?
+        .len())
+}
+
+/// Delete chunk files, ignoring ones that are already gone
+pub async fn remove_chunks(chunks: &[AudioChunk]) {
+    for chunk in chunks {
+        let _ = tokio::fs::remove_file(&chunk.path).await;
+    }
+}
+
+/// Join per-chunk transcripts into one, shifting each by its chunk's offset.
+/// A cue running past the start of the next chunk is cut short there.
+pub fn stitch(parts: Vec<(SubtitleDocument, u64)>) -> SubtitleDocument {
+    let next_offsets: Vec<Option<u64>> = parts
+        .iter()
+        .skip(1)
+        .map(|(_, offset_ms)| Some(*offset_ms))
+        .chain(std::iter::once(None))
+        .collect();
+
+    let mut cues = Vec::new();
+    for ((document, offset_ms), next_offset) in parts.into_iter().zip(next_offsets) {
+        for mut cue in document.cues {
+            cue.start_ms += offset_ms;
+            cue.end_ms += offset_ms;
+            if let Some(next_offset) = next_offset {
+                if cue.start_ms >= next_offset {
+                    continue;
+                }
+                cue.end_ms = cue.end_ms.min(next_offset);
+            }
+            cues.push(cue);
+        }
+    }
+
+    SubtitleDocument::new(cues)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subtitle::Cue;
+
+    #[test]
+    fn cuts_at_latest_pause_before_limit() {
+        // The pause at 900s is too far before the second limit (1151s) to be used
+        let silences = [
+            (100.0, 101.0),
+            (550.0, 552.0),
+            (900.0, 902.0),
+            (1100.0, 1102.0),
+        ];
+        let chunks = plan_chunks(1500.0, 600.0, &silences);
+
+        assert_eq!(chunks, vec![0.0..551.0, 551.0..1101.0, 1101.0..1500.0]);
+    }
+
+    #[test]
+    fn cuts_at_limit_without_pauses() {
+        assert_eq!(
+            plan_chunks(1300.0, 600.0, &[]),
+            vec![0.0..600.0, 600.0..1200.0, 1200.0..1300.0]
+        );
+        assert_eq!(plan_chunks(300.0, 600.0, &[]), vec![0.0..300.0]);
+    }
+
+    #[test]
+    fn pairs_silence_log_lines() {
+        let log = "[silencedetect @ 0x1] silence_start: 10.5\n\
+                   size=N/A time=00:00:12.00 bitrate=N/A\n\
+                   [silencedetect @ 0x1] silence_end: 12.25 | silence_duration: 1.75\n\
+                   [silencedetect @ 0x1] silence_start: 58.1\n";
+
+        assert_eq!(
+            ffmpeg::parse_silences(log, 60.0),
+            vec![(10.5, 12.25), (58.1, 60.0)]
+        );
+    }
+
+    #[test]
+    fn stitches_with_offsets() {
+        let first = SubtitleDocument::new(vec![
+            Cue::new(0, 1000, "One"),
+            Cue::new(59_500, 61_000, "Two"),
+        ]);
+        let second = SubtitleDocument::new(vec![Cue::new(500, 2000, "Three")]);
+
+        let stitched = stitch(vec![(first, 0), (second, 60_000)]);
+        let spans: Vec<(u64, u64, &str)> = stitched
+            .cues
+            .iter()
+            .map(|cue| (cue.start_ms, cue.end_ms, cue.text.as_str()))
+            .collect();
+
+        assert_eq!(
+            spans,
+            vec![
+                (0, 1000, "One"),
+                (59_500, 60_000, "Two"),
+                (60_500, 62_000, "Three")
+            ]
+        );
+    }
+}
@@ -1,16 +1,35 @@
-use anyhow::{Context, Result};
+use anyhow::{Context as _, Result};
+use base64::prelude::*;
 use once_cell::sync::Lazy;
+use reqwest::header::{ACCEPT, CONTENT_LENGTH, CONTENT_TYPE, LOCATION};
+use reqwest::StatusCode;
 use serde::{Deserialize, Serialize};
+use std::io::SeekFrom;
 use std::path::Path;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::task::{Context, Poll};
 use std::time::{Duration, Instant};
-use tauri::{AppHandle, Emitter, Manager, Window};
+use tauri::{AppHandle, Emitter, Window};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncSeekExt, ReadBuf};
+use tokio_util::io::ReaderStream;
+use tokio_util::sync::CancellationToken;
+
+use crate::ffmpeg::{self, TaskProgressPayload};
+use crate::job_store::{self, PendingUpload};
+use crate::sse::SseDecoder;
+use crate::task_registry;
+use crate::transcription::{JobStatus, StatusReceiver, TranscriptionProvider};
 
-const POLL_INTERVAL_SECS: u64 = 3;
-const MAX_POLL_ATTEMPTS: u32 = 600; // 30 minutes max (600 * 3 seconds)
 const MAX_RETRIES: u32 = 3;
 const INITIAL_RETRY_DELAY_MS: u64 = 1000; // Start with 1 second
-const MAX_FILE_SIZE_BYTES: u64 = 500 * 1024 * 1024; // 500 MB
+pub const MAX_FILE_SIZE_BYTES: u64 = 500 * 1024 * 1024; // 500 MB
 const UPLOAD_TIMEOUT_SECS: u64 = 900; // 15 minutes for local testing of large file uploads
+const UPLOAD_BUFFER_SIZE: usize = 256 * 1024; // Bytes read from disk per body chunk
+const UPLOAD_CHUNK_BYTES: u64 = 8 * 1024 * 1024; // Bytes per resumable upload request
+const UPLOAD_CHUNK_TIMEOUT_SECS: u64 = 120;
+const TUS_VERSION: &str = "1.0.0";
+const UPLOAD_PROGRESS_INTERVAL_MS: u64 = 500;
 const POLL_TIMEOUT_SECS: u64 = 10; // 10 seconds for status polling
 const DOWNLOAD_TIMEOUT_SECS: u64 = 120; // 2 minutes for SRT download
 
@@ -23,6 +42,15 @@ static HTTP_CLIENT: Lazy<reqwest::Client> = Lazy::new(|| {
         .expect("Failed to create HTTP client")
 });
 
+// Status event streams stay open for the whole transcription, so their client has no
+// total timeout; a quiet stream is left to the caller's idle fallback
+static EVENT_STREAM_CLIENT: Lazy<reqwest::Client> = Lazy::new(|| {
+    reqwest::Client::builder()
+        .connect_timeout(Duration::from_secs(POLL_TIMEOUT_SECS))
+        .build()
+        .expect("Failed to create HTTP client")
+});
+
 #[derive(Debug, Serialize, Deserialize)]
 struct CreateTranscriptionResponse {
     job_id: String,
@@ -41,12 +69,25 @@ struct TranscriptionStatusResponse {
     language_detection: bool,
     speaker_labels: bool,
     srt_available: bool,
+    /// Language detected in the audio, set once the job has completed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    language_code: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     error_message: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     completed_at: Option<String>,
 }
 
+/// Data of a `status` event on the job's event stream
+#[derive(Debug, Deserialize)]
+struct StatusEvent {
+    status: String,
+    #[serde(default)]
+    language_code: Option<String>,
+    #[serde(default)]
+    error_message: Option<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 struct ApiErrorResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -143,12 +184,14 @@ fn format_error_for_log(error: &anyhow::Error) -> String {
 }
 
 /// Retry a function with exponential backoff
+/// Cancelling the token aborts the in-flight attempt and skips any remaining retries
 async fn retry_with_backoff<F, Fut, T>(
     mut operation: F,
     operation_name: &str,
     task_id: &str,
     window: &Window,
     app_handle: &AppHandle,
+    cancel_token: &CancellationToken,
 ) -> Result<T>
 where
     F: FnMut() -> Fut,
@@ -160,8 +203,9 @@ where
         let attempt_number = attempt + 1;
         let started_at = Instant::now();
 
-        match operation().await {
+        match task_registry::run_cancellable(cancel_token, operation()).await {
             Ok(result) => return Ok(result),
+            Err(e) if task_registry::is_cancelled(&e) => return Err(e),
             Err(e) => {
                 let elapsed_ms = started_at.elapsed().as_millis();
                 let error_summary = format_error_for_log(&e);
@@ -188,7 +232,8 @@ where
                     )
                     .await;
 
-                    tokio::time::sleep(Duration::from_millis(delay)).await;
+                    task_registry::sleep_cancellable(cancel_token, Duration::from_millis(delay))
+                        .await?;
                 } else {
                     let _ = crate::logger::append_log_entry(
                         app_handle,
@@ -213,34 +258,6 @@ where
     )))
 }
 
-#[derive(Debug, Clone, Serialize)]
-pub struct TranscriptionStartedPayload {
-    #[serde(rename = "taskId")]
-    pub task_id: String,
-    #[serde(rename = "transcriptId")]
-    pub transcript_id: String,
-}
-
-#[derive(Debug, Clone, Serialize)]
-pub struct TranscriptionPollingPayload {
-    #[serde(rename = "taskId")]
-    pub task_id: String,
-    pub status: String,
-}
-
-/// Payload for transcription complete event
-#[derive(Debug, Clone, Serialize)]
-pub struct TranscriptionCompletePayload {
-    #[serde(rename = "taskId")]
-    pub task_id: String,
-    /// Path to the temporary audio file (will be cleaned up)
-    #[serde(rename = "audioPath")]
-    pub audio_path: String,
-    /// Path to the final SRT transcript file
-    #[serde(rename = "transcriptPath")]
-    pub transcript_path: String,
-}
-
 /// Validate backend URL is accessible with a health check
 pub async fn validate_backend(backend_url: &str) -> Result<()> {
     let response = HTTP_CLIENT
@@ -260,6 +277,77 @@ pub async fn validate_backend(backend_url: &str) -> Result<()> {
     Ok(())
 }
 
+/// Audio file (or a slice of it) being read into an upload body, emitting throttled
+/// `task:progress` events (stage "uploading") as bytes are handed to the HTTP client
+struct UploadProgressReader<R> {
+    file: R,
+    /// Bytes of the whole file sent so far, including earlier requests
+    bytes_sent: u64,
+    total_bytes: u64,
+    last_emit: Option<Instant>,
+    task_id: String,
+    window: Window,
+}
+
+impl<R: AsyncRead + Unpin> UploadProgressReader<R> {
+    fn new(file: R, bytes_sent: u64, total_bytes: u64, task_id: &str, window: &Window) -> Self {
+        Self {
+            file,
+            bytes_sent,
+            total_bytes,
+            last_emit: None,
+            task_id: task_id.to_string(),
+            window: window.clone(),
+        }
+    }
+
+    fn emit_progress(&mut self) {
+        let done = self.bytes_sent >= self.total_bytes;
+        let due = self.last_emit.is_none_or(|last| {
+            last.elapsed() >= Duration::from_millis(UPLOAD_PROGRESS_INTERVAL_MS)
+        });
+        if !due && !done {
+            return;
+        }
+        self.last_emit = Some(Instant::now());
+
+        let percent = if self.total_bytes == 0 {
+            100.0
+        } else {
+            (self.bytes_sent as f64 / self.total_bytes as f64 * 100.0).min(100.0)
+        };
+        let _ = self.window.emit(
+            "task:progress",
+            TaskProgressPayload {
+                task_id: self.task_id.clone(),
+                stage: "uploading".to_string(),
+                percent: (percent * 10.0).round() / 10.0,
+            },
+        );
+    }
+}
+
+impl<R: AsyncRead + Unpin> AsyncRead for UploadProgressReader<R> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<std::io::Result<()>> {
+        let this = self.get_mut();
+        let filled_before = buf.filled().len();
+        let result = Pin::new(&mut this.file).poll_read(cx, buf);
+
+        if let Poll::Ready(Ok(())) = result {
+            let read = buf.filled().len() - filled_before;
+            if read > 0 {
+                this.bytes_sent += read as u64;
+                this.emit_progress();
+            }
+        }
+        result
+    }
+}
+
 /// Upload audio file to backend transcription service
 async fn upload_audio(
     backend_url: &str,
@@ -267,6 +355,7 @@ async fn upload_audio(
     task_id: &str,
     window: &Window,
     app_handle: &AppHandle,
+    cancel_token: &CancellationToken,
 ) -> Result<String> {
     // Check file size before processing
     let metadata = tokio::fs::metadata(audio_path)
@@ -278,7 +367,7 @@ async fn upload_audio(
         let size_mb = file_size as f64 / (1024.0 * 1024.0);
         let max_mb = MAX_FILE_SIZE_BYTES as f64 / (1024.0 * 1024.0);
         anyhow::bail!(
-            "Audio file too large: {:.1} MB (max: {:.0} MB)",
+            "Audio file too large: {:.1} MB (max: {:.0} MB). Extract as FLAC or Opus to shrink it",
             size_mb,
             max_mb
         );
@@ -297,63 +386,66 @@ async fn upload_audio(
     )
     .await?;
 
-    // Read the audio file
-    let file_bytes = tokio::fs::read(audio_path)
-        .await
-        .context("Failed to read audio file")?;
-
     let audio_filename = Path::new(audio_path)
         .file_name()
         .and_then(|n| n.to_str())
         .unwrap_or("audio.wav");
+    let content_type = ffmpeg::audio_content_type(Path::new(audio_path));
+
+    // Prefer a resumable upload; backends without one get the whole file in the create request
+    let upload_url = upload_resumable(
+        backend_url,
+        audio_path,
+        file_size,
+        audio_filename,
+        task_id,
+        window,
+        app_handle,
+        cancel_token,
+    )
+    .await?;
 
     let backend_url = backend_url.to_string();
     let audio_filename = audio_filename.to_string();
-    let file_bytes_clone = file_bytes.clone();
 
-    // Upload with retry logic
-    let job_id = retry_with_backoff(
-        || {
-            let backend_url = backend_url.clone();
-            let audio_filename = audio_filename.clone();
-            let file_bytes = file_bytes_clone.clone();
-            async move {
-                // Create multipart form
-                let part = reqwest::multipart::Part::bytes(file_bytes).file_name(audio_filename);
+    let job_id = if let Some(upload_url) = upload_url {
+        // The job is created from the finished upload (see "Backend Setup" in the README)
+        let job_id = retry_with_backoff(
+            || {
                 let form = reqwest::multipart::Form::new()
-                    .part("file", part)
+                    .text("upload_url", upload_url.clone())
                     .text("language_detection", "true")
                     .text("speaker_labels", "true");
+                create_transcription(&backend_url, form, POLL_TIMEOUT_SECS)
+            },
+            "Create transcription",
+            task_id,
+            window,
+            app_handle,
+            cancel_token,
+        )
+        .await?;
 
-                let response = HTTP_CLIENT
-                    .post(format!("{}/transcriptions", backend_url))
-                    .timeout(Duration::from_secs(UPLOAD_TIMEOUT_SECS))
-                    .multipart(form)
-                    .send()
-                    .await
-                    .context("Network error during audio upload")?;
-
-                if !response.status().is_success() {
-                    let status = response.status();
-                    let error_text = response.text().await.unwrap_or_default();
-                    let parsed_error = parse_api_error(&error_text, "Upload failed");
-                    anyhow::bail!("[HTTP {}] {}", status, parsed_error);
-                }
-
-                let create_response: CreateTranscriptionResponse = response
-                    .json()
-                    .await
-                    .context("Failed to parse create transcription response")?;
-
-                Ok(create_response.job_id)
-            }
-        },
-        "Upload audio",
-        task_id,
-        window,
-        app_handle,
-    )
-    .await?;
+        let audio_path_key = audio_path.to_string();
+        let _ = job_store::update_job(app_handle, task_id, |job| {
+            job.uploads.remove(&audio_path_key);
+        })
+        .await;
+        job_id
+    } else {
+        upload_whole_file(
+            &backend_url,
+            audio_path,
+            file_size,
+            &audio_filename,
+            content_type,
+            task_id,
+            window,
+            app_handle,
+            cancel_token,
+        )
+        .await?
+    };
 
     crate::logger::append_log_entry(
         app_handle,
@@ -367,141 +459,511 @@ async fn upload_audio(
     Ok(job_id)
 }
 
-/// Poll transcription status until completion or error
-async fn poll_transcription_status(
+/// Create a transcription job from a multipart form; returns the job ID
+async fn create_transcription(
     backend_url: &str,
-    job_id: &str,
+    form: reqwest::multipart::Form,
+    timeout_secs: u64,
+) -> Result<String> {
+    let response = HTTP_CLIENT
+        .post(format!("{}/transcriptions", backend_url))
+        .timeout(Duration::from_secs(timeout_secs))
+        .multipart(form)
+        .send()
+        .await
+        .context("Network error during audio upload")?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let error_text = response.text().await.unwrap_or_default();
+        let parsed_error = parse_api_error(&error_text, "Upload failed");
+        anyhow::bail!("[HTTP {}] {}", status, parsed_error);
+    }
+
+    let create_response: CreateTranscriptionResponse = response
+        .json()
+        .await
+        .context("Failed to parse create transcription response")?;
+
+    Ok(create_response.job_id)
+}
+
+/// Send the whole file with the create request, for backends without resumable uploads
+#[allow(clippy::too_many_arguments)]
+async fn upload_whole_file(
+    backend_url: &str,
+    audio_path: &str,
+    file_size: u64,
+    audio_filename: &str,
+    content_type: &str,
     task_id: &str,
     window: &Window,
     app_handle: &AppHandle,
-) -> Result<()> {
-    let mut attempts = 0;
-
-    loop {
-        if attempts >= MAX_POLL_ATTEMPTS {
-            anyhow::bail!(
-                "Transcription timeout: exceeded maximum polling attempts (Job ID: {})",
-                job_id
-            );
-        }
+    cancel_token: &CancellationToken,
+) -> Result<String> {
+    // Upload with retry logic; each attempt streams the file from disk again
+    retry_with_backoff(
+        || {
+            let audio_filename = audio_filename.to_string();
+            async move {
+                let file = tokio::fs::File::open(audio_path)
+                    .await
+                    .context("Failed to open audio file")?;
+                let reader = UploadProgressReader::new(file, 0, file_size, task_id, window);
+                let body = reqwest::Body::wrap_stream(ReaderStream::with_capacity(
+                    reader,
+                    UPLOAD_BUFFER_SIZE,
+                ));
 
-        tokio::time::sleep(Duration::from_secs(POLL_INTERVAL_SECS)).await;
-        attempts += 1;
+                // Create multipart form
+                let part = reqwest::multipart::Part::stream_with_length(body, file_size)
+                    .file_name(audio_filename)
+                    .mime_str(content_type)?;
+                let form = reqwest::multipart::Form::new()
+                    .part("file", part)
+                    .text("language_detection", "true")
+                    .text("speaker_labels", "true");
 
-        // Poll with retry logic (network errors only, not status errors)
-        let status_response = retry_with_backoff(
-            || {
-                let backend_url = backend_url.to_string();
-                let job_id = job_id.to_string();
-                async move {
-                    let response = HTTP_CLIENT
-                        .get(format!("{}/transcriptions/{}", backend_url, job_id))
-                        .timeout(Duration::from_secs(POLL_TIMEOUT_SECS))
-                        .send()
-                        .await
-                        .context("Network error during status polling")?;
-
-                    if !response.status().is_success() {
-                        let status = response.status();
-                        let error_text = response.text().await.unwrap_or_default();
-                        let parsed_error = parse_api_error(&error_text, "Status polling failed");
-                        anyhow::bail!("[HTTP {}] {} (Job ID: {})", status, parsed_error, job_id);
-                    }
+                create_transcription(backend_url, form, UPLOAD_TIMEOUT_SECS).await
+            }
+        },
+        "Upload audio",
+        task_id,
+        window,
+        app_handle,
+        cancel_token,
+    )
+    .await
+}
 
-                    let status_response: TranscriptionStatusResponse = response
-                        .json()
-                        .await
-                        .context("Failed to parse status response")?;
+/// Resolve a `Location` header, which may be relative, against the uploads endpoint
+fn resolve_upload_url(backend_url: &str, location: &str) -> Result<String> {
+    let base =
+        reqwest::Url::parse(&format!("{}/uploads", backend_url)).context("Invalid backend URL")?;
+    let url = base
+        .join(location)
+        .context("Invalid upload location from backend")?;
+    Ok(url.to_string())
+}
 
-                    Ok(status_response)
-                }
-            },
-            "Poll transcription status",
-            task_id,
-            window,
-            app_handle,
+/// Start a tus upload for `file_size` bytes
+/// Returns None if the backend does not offer resumable uploads (400, 404, 405 or 501)
+async fn create_upload(
+    backend_url: &str,
+    file_size: u64,
+    audio_filename: &str,
+) -> Result<Option<String>> {
+    let response = HTTP_CLIENT
+        .post(format!("{}/uploads", backend_url))
+        .timeout(Duration::from_secs(POLL_TIMEOUT_SECS))
+        .header("Tus-Resumable", TUS_VERSION)
+        .header("Upload-Length", file_size.to_string())
+        .header(
+            "Upload-Metadata",
+            format!("filename {}", BASE64_STANDARD.encode(audio_filename)),
         )
-        .await?;
+        .send()
+        .await
+        .context("Network error while creating upload")?;
+
+    // Backends without tus answer 400 (unknown headers), 404, 405 or 501; other
+    // errors such as 401/403 (credentials) or 413 (too large) are real failures
+    let status = response.status();
+    if matches!(
+        status,
+        StatusCode::BAD_REQUEST
+            | StatusCode::NOT_FOUND
+            | StatusCode::METHOD_NOT_ALLOWED
+            | StatusCode::NOT_IMPLEMENTED
+    ) {
+        return Ok(None);
+    }
+    if !status.is_success() {
+        let error_text = response.text().await.unwrap_or_default();
+        let parsed_error = parse_api_error(&error_text, "Creating upload failed");
+        anyhow::bail!("[HTTP {}] {}", status, parsed_error);
+    }
 
-        // Log status
-        crate::logger::append_log_entry(
-            app_handle,
-            window,
+    let location = response
+        .headers()
+        .get(LOCATION)
+        .and_then(|value| value.to_str().ok())
+        .context("Backend did not return an upload location")?;
+    resolve_upload_url(backend_url, location).map(Some)
+}
+
+/// Bytes of an upload the backend has stored, or None if the upload no longer exists
+async fn fetch_upload_offset(upload_url: &str) -> Result<Option<u64>> {
+    let response = HTTP_CLIENT
+        .head(upload_url)
+        .timeout(Duration::from_secs(POLL_TIMEOUT_SECS))
+        .header("Tus-Resumable", TUS_VERSION)
+        .send()
+        .await
+        .context("Network error while checking upload offset")?;
+
+    let status = response.status();
+    if matches!(
+        status,
+        StatusCode::NOT_FOUND | StatusCode::GONE | StatusCode::FORBIDDEN
+    ) {
+        return Ok(None);
+    }
+    if !status.is_success() {
+        anyhow::bail!("[HTTP {}] Checking upload offset failed", status);
+    }
+
+    upload_offset_header(&response).map(Some)
+}
+
+fn upload_offset_header(response: &reqwest::Response) -> Result<u64> {
+    response
+        .headers()
+        .get("Upload-Offset")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse().ok())
+        .context("Backend did not return a valid Upload-Offset")
+}
+
+/// Send up to UPLOAD_CHUNK_BYTES of the file starting at `offset`; returns the new offset
+async fn patch_upload(
+    upload_url: &str,
+    audio_path: &str,
+    offset: u64,
+    file_size: u64,
+    task_id: &str,
+    window: &Window,
+) -> Result<u64> {
+    let len = UPLOAD_CHUNK_BYTES.min(file_size - offset);
+
+    let mut file = tokio::fs::File::open(audio_path)
+        .await
+        .context("Failed to open audio file")?;
+    file.seek(SeekFrom::Start(offset))
+        .await
+        .context("Failed to seek in audio file")?;
+    let reader = UploadProgressReader::new(file.take(len), offset, file_size, task_id, window);
+    let body = reqwest::Body::wrap_stream(ReaderStream::with_capacity(reader, UPLOAD_BUFFER_SIZE));
+
+    let response = HTTP_CLIENT
+        .patch(upload_url)
+        .timeout(Duration::from_secs(UPLOAD_CHUNK_TIMEOUT_SECS))
+        .header("Tus-Resumable", TUS_VERSION)
+        .header("Upload-Offset", offset.to_string())
+        .header(CONTENT_TYPE, "application/offset+octet-stream")
+        .header(CONTENT_LENGTH, len.to_string())
+        .body(body)
+        .send()
+        .await
+        .context("Network error during audio upload")?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let error_text = response.text().await.unwrap_or_default();
+        let parsed_error = parse_api_error(&error_text, "Upload failed");
+        anyhow::bail!("[HTTP {}] {}", status, parsed_error);
+    }
+
+    upload_offset_header(&response)
+}
+
+/// Upload the file with the tus resumable upload protocol, in UPLOAD_CHUNK_BYTES requests.
+/// The upload URL and confirmed offset are saved to the job record after every chunk, so
+/// retries and restarted apps continue from the last confirmed byte instead of byte zero.
+/// Returns the upload URL once the backend has the whole file, or None if the backend
+/// does not support resumable uploads.
+#[allow(clippy::too_many_arguments)]
+async fn upload_resumable(
+    backend_url: &str,
+    audio_path: &str,
+    file_size: u64,
+    audio_filename: &str,
+    task_id: &str,
+    window: &Window,
+    app_handle: &AppHandle,
+    cancel_token: &CancellationToken,
+) -> Result<Option<String>> {
+    // Continue an upload started before a restart, if the backend still has it
+    let pending = job_store::load_job(app_handle, task_id)
+        .await
+        .ok()
+        .flatten()
+        .and_then(|job| job.uploads.get(audio_path).cloned())
+        .filter(|upload| upload.size == file_size);
+
+    let mut resumed = None;
+    if let Some(upload) = pending {
+        let offset = retry_with_backoff(
+            || fetch_upload_offset(&upload.url),
+            "Check upload offset",
             task_id,
-            "transcription",
-            &format!(
-                "Poll attempt {}: Status = {} (Job ID: {})",
-                attempts, status_response.status, job_id
-            ),
+            window,
+            app_handle,
+            cancel_token,
         )
         .await?;
+        if let Some(offset) = offset {
+            resumed = Some((upload.url, offset));
+        }
+    }
 
-        // Emit polling event
-        window
-            .emit(
-                "transcription:polling",
-                TranscriptionPollingPayload {
-                    task_id: task_id.to_string(),
-                    status: status_response.status.clone(),
-                },
+    let (upload_url, mut offset) = match resumed {
+        Some((upload_url, offset)) => {
+            crate::logger::append_log_entry(
+                app_handle,
+                window,
+                task_id,
+                "transcription",
+                &format!(
+                    "Resuming upload at {:.2} of {:.2} MB",
+                    offset as f64 / (1024.0 * 1024.0),
+                    file_size as f64 / (1024.0 * 1024.0)
+                ),
             )
-            .ok();
-
-        match status_response.status.as_str() {
-            "completed" => {
+            .await?;
+            (upload_url, offset)
+        }
+        None => {
+            let created = retry_with_backoff(
+                || create_upload(backend_url, file_size, audio_filename),
+                "Create upload",
+                task_id,
+                window,
+                app_handle,
+                cancel_token,
+            )
+            .await?;
+            let Some(upload_url) = created else {
                 crate::logger::append_log_entry(
                     app_handle,
                     window,
                     task_id,
                     "transcription",
-                    &format!("Transcription completed successfully! (Job ID: {})", job_id),
+                    "Backend has no resumable uploads, sending the whole file",
                 )
                 .await?;
-                return Ok(());
-            }
-            "error" => {
-                let error_msg = status_response
-                    .error_message
-                    .unwrap_or_else(|| "Unknown error".to_string());
-                anyhow::bail!("Transcription failed (Job ID: {}): {}", job_id, error_msg);
-            }
-            "queued" | "processing" => {
-                // Continue polling
-                continue;
-            }
-            _ => {
-                crate::logger::append_log_entry(
-                    app_handle,
-                    window,
+                return Ok(None);
+            };
+            (upload_url, 0)
+        }
+    };
+
+    // After a failed attempt the backend may have stored part of the chunk
+    let resync = AtomicBool::new(false);
+    while offset < file_size {
+        let upload_url_ref = &upload_url;
+        let resync_ref = &resync;
+        offset = retry_with_backoff(
+            || async move {
+                let mut start = offset;
+                if resync_ref.swap(true, Ordering::Relaxed) {
+                    start = fetch_upload_offset(upload_url_ref)
+                        .await?
+                        .context("Upload expired on the backend")?;
+                    if start >= file_size {
+                        return Ok(start);
+                    }
+                }
+                let new_offset = patch_upload(
+                    upload_url_ref,
+                    audio_path,
+                    start,
+                    file_size,
                     task_id,
-                    "transcription",
-                    &format!(
-                        "Unknown status: {} (Job ID: {})",
-                        status_response.status, job_id
-                    ),
+                    window,
                 )
                 .await?;
-                continue;
+                // A backend that keeps the offset where it was would loop forever
+                if new_offset <= start {
+                    anyhow::bail!(
+                        "Backend accepted none of the upload chunk at byte {}",
+                        start
+                    );
+                }
+                Ok(new_offset)
+            },
+            "Upload audio chunk",
+            task_id,
+            window,
+            app_handle,
+            cancel_token,
+        )
+        .await?;
+        resync.store(false, Ordering::Relaxed);
+
+        let audio_path_key = audio_path.to_string();
+        let upload = PendingUpload {
+            url: upload_url.clone(),
+            offset,
+            size: file_size,
+        };
+        let _ = job_store::update_job(app_handle, task_id, |job| {
+            job.uploads.insert(audio_path_key, upload);
+        })
+        .await;
+    }
+
+    Ok(Some(upload_url))
+}
+
+/// Map the backend's status fields to a job status
+fn job_status(
+    status: String,
+    language_code: Option<String>,
+    error_message: Option<String>,
+) -> JobStatus {
+    match status.as_str() {
+        "completed" => JobStatus::Completed {
+            language_code: language_code.filter(|code| !code.trim().is_empty()),
+        },
+        "error" => JobStatus::Failed {
+            message: error_message.unwrap_or_else(|| "Unknown error".to_string()),
+        },
+        _ => JobStatus::Pending { status },
+    }
+}
+
+/// Open the job's `/events` stream and forward its status events to a channel
+/// Returns None if the backend has no event stream, so the caller polls instead.
+/// The reader task ends with the stream, or as soon as the receiver is dropped
+/// (e.g. when the caller falls back to polling), closing the connection.
+async fn open_status_events(backend_url: &str, job_id: &str) -> Result<Option<StatusReceiver>> {
+    let request = EVENT_STREAM_CLIENT
+        .get(format!("{}/transcriptions/{}/events", backend_url, job_id))
+        .header(ACCEPT, "text/event-stream")
+        .send();
+    let response = tokio::time::timeout(Duration::from_secs(POLL_TIMEOUT_SECS), request)
+        .await
+        .context("Timed out waiting for status events to open")?
+        .context("Network error while opening status events")?;
+
+    let status = response.status();
+    if matches!(
+        status,
+        StatusCode::NOT_FOUND
+            | StatusCode::METHOD_NOT_ALLOWED
+            | StatusCode::NOT_ACCEPTABLE
+            | StatusCode::NOT_IMPLEMENTED
+    ) {
+        return Ok(None);
+    }
+    if !status.is_success() {
+        anyhow::bail!("[HTTP {}] Opening status events failed", status);
+    }
+    let is_event_stream = response
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("text/event-stream"));
+    if !is_event_stream {
+        return Ok(None);
+    }
+
+    let (sender, receiver) = tokio::sync::mpsc::channel(16);
+    let mut response = response;
+    tokio::spawn(async move {
+        let mut decoder = SseDecoder::default();
+        loop {
+            let chunk = tokio::select! {
+                chunk = response.chunk() => chunk,
+                _ = sender.closed() => return,
+            };
+            let chunk = match chunk {
+                Ok(Some(chunk)) => chunk,
+                Ok(None) => return,
+                Err(e) => {
+                    let _ = sender
+                        .send(Err(
+                            anyhow::Error::new(e).context("Status event stream failed")
+                        ))
+                        .await;
+                    return;
+                }
+            };
+
+            for event in decoder.push(&chunk) {
+                // Other event types (e.g. progress details) are not needed here
+                if !matches!(
+                    event.event.as_deref(),
+                    None | Some("message") | Some("status")
+                ) {
+                    continue;
+                }
+                let update = serde_json::from_str::<StatusEvent>(&event.data)
+                    .map(|update| {
+                        job_status(update.status, update.language_code, update.error_message)
+                    })
+                    .context("Failed to parse status event");
+                if sender.send(update).await.is_err() {
+                    return;
+                }
             }
         }
-    }
+    });
+
+    Ok(Some(receiver))
 }
 
-/// Download SRT subtitle file to temp folder with -original.srt suffix
+/// Fetch a job's status, retrying failed requests with exponential backoff
+async fn fetch_status(
+    backend_url: &str,
+    job_id: &str,
+    task_id: &str,
+    window: &Window,
+    app_handle: &AppHandle,
+    cancel_token: &CancellationToken,
+) -> Result<TranscriptionStatusResponse> {
+    retry_with_backoff(
+        || {
+            let backend_url = backend_url.to_string();
+            let job_id = job_id.to_string();
+            async move {
+                let response = HTTP_CLIENT
+                    .get(format!("{}/transcriptions/{}", backend_url, job_id))
+                    .timeout(Duration::from_secs(POLL_TIMEOUT_SECS))
+                    .send()
+                    .await
+                    .context("Network error during status polling")?;
+
+                if !response.status().is_success() {
+                    let status = response.status();
+                    let error_text = response.text().await.unwrap_or_default();
+                    let parsed_error = parse_api_error(&error_text, "Status polling failed");
+                    anyhow::bail!("[HTTP {}] {} (Job ID: {})", status, parsed_error, job_id);
+                }
+
+                let status_response: TranscriptionStatusResponse = response
+                    .json()
+                    .await
+                    .context("Failed to parse status response")?;
+
+                Ok(status_response)
+            }
+        },
+        "Poll transcription status",
+        task_id,
+        window,
+        app_handle,
+        cancel_token,
+    )
+    .await
+}
+
+/// Download a completed job's SRT content
 async fn download_srt(
     backend_url: &str,
     job_id: &str,
-    temp_srt_path: &str,
     task_id: &str,
     window: &Window,
     app_handle: &AppHandle,
-) -> Result<()> {
+    cancel_token: &CancellationToken,
+) -> Result<String> {
     crate::logger::append_log_entry(
         app_handle,
         window,
         task_id,
         "transcription",
-        "Downloading original SRT subtitle file to temp folder...",
+        "Downloading original SRT subtitle file...",
     )
     .await?;
 
@@ -509,7 +971,7 @@ async fn download_srt(
     let job_id_str = job_id.to_string();
 
     // Download with retry logic
-    let srt_content = retry_with_backoff(
+    retry_with_backoff(
         || {
             let backend_url = backend_url.clone();
             let job_id = job_id_str.clone();
@@ -540,120 +1002,97 @@ async fn download_srt(
         task_id,
         window,
         app_handle,
+        cancel_token,
     )
-    .await?;
-
-    // Write SRT file to temp location
-    tokio::fs::write(temp_srt_path, srt_content)
-        .await
-        .context("Failed to write SRT file")?;
-
-    crate::logger::append_log_entry(
-        app_handle,
-        window,
-        task_id,
-        "transcription",
-        &format!(
-            "Original SRT file saved to temp: {} (Job ID: {})",
-            temp_srt_path, job_id
-        ),
-    )
-    .await?;
+    .await
+}
 
-    Ok(())
+/// The transcription backend at the batch's backend URL (`/transcriptions` endpoints)
+pub struct BackendProvider {
+    backend_url: String,
 }
 
-/// Main transcription orchestration function
-/// Returns the path to the original SRT file in the temp directory (for translation)
-pub async fn transcribe_audio(
-    backend_url: &str,
-    task_id: &str,
-    audio_path: &str,
-    original_file_path: &str,
-    window: &Window,
-    app_handle: &AppHandle,
-) -> Result<String> {
-    // Get the base filename without extension from the ORIGINAL file, not the temp audio file
-    let original_file = Path::new(original_file_path);
-    let file_stem = original_file
-        .file_stem()
-        .context("Failed to get file name")?
-        .to_str()
-        .context("Invalid file name")?;
-
-    // Create temp directory for original SRT (will be translated later)
-    let temp_dir = app_handle
-        .path()
-        .temp_dir()
-        .context("Failed to get temp directory")?;
-
-    let srt_temp_dir = temp_dir.join("translation-app-srt");
-    std::fs::create_dir_all(&srt_temp_dir).context("Failed to create SRT temp directory")?;
-
-    // Save with -original.srt suffix in temp folder
-    let temp_srt_path = srt_temp_dir.join(format!("{}_{}-original.srt", task_id, file_stem));
-    let temp_srt_path_str = temp_srt_path
-        .to_str()
-        .context("Invalid temp SRT path")?
-        .to_string();
+impl BackendProvider {
+    pub fn new(backend_url: &str) -> Self {
+        Self {
+            backend_url: backend_url.to_string(),
+        }
+    }
+}
 
-    crate::logger::append_log_entry(
-        app_handle,
-        window,
-        task_id,
-        "transcription",
-        &format!("Starting transcription for: {}", audio_path),
-    )
-    .await?;
+impl TranscriptionProvider for BackendProvider {
+    fn name(&self) -> &str {
+        "transcription backend"
+    }
 
-    // Step 1: Upload audio and create transcription job
-    let job_id = upload_audio(backend_url, audio_path, task_id, window, app_handle).await?;
+    fn is_resumable(&self) -> bool {
+        true
+    }
 
-    // Emit transcription started event AFTER logs are written
-    window
-        .emit(
-            "transcription:started",
-            TranscriptionStartedPayload {
-                task_id: task_id.to_string(),
-                transcript_id: job_id.clone(),
-            },
+    async fn submit(
+        &self,
+        audio_path: &str,
+        task_id: &str,
+        window: &Window,
+        app_handle: &AppHandle,
+        cancel_token: &CancellationToken,
+    ) -> Result<String> {
+        upload_audio(
+            &self.backend_url,
+            audio_path,
+            task_id,
+            window,
+            app_handle,
+            cancel_token,
         )
-        .context("Failed to emit transcription:started event")?;
+        .await
+    }
 
-    // Step 2: Poll until complete
-    poll_transcription_status(backend_url, &job_id, task_id, window, app_handle).await?;
+    async fn poll(
+        &self,
+        job_id: &str,
+        task_id: &str,
+        window: &Window,
+        app_handle: &AppHandle,
+        cancel_token: &CancellationToken,
+    ) -> Result<JobStatus> {
+        let status_response = fetch_status(
+            &self.backend_url,
+            job_id,
+            task_id,
+            window,
+            app_handle,
+            cancel_token,
+        )
+        .await?;
 
-    // Step 3: Download SRT to temp folder
-    download_srt(
-        backend_url,
-        &job_id,
-        &temp_srt_path_str,
-        task_id,
-        window,
-        app_handle,
-    )
-    .await?;
+        Ok(job_status(
+            status_response.status,
+            status_response.language_code,
+            status_response.error_message,
+        ))
+    }
 
-    crate::logger::append_log_entry(
-        app_handle,
-        window,
-        task_id,
-        "transcription",
-        "Transcription completed! Original SRT ready for translation.",
-    )
-    .await?;
+    async fn subscribe(&self, job_id: &str) -> Result<Option<StatusReceiver>> {
+        open_status_events(&self.backend_url, job_id).await
+    }
 
-    // Emit transcription complete event
-    window
-        .emit(
-            "transcription:complete",
-            TranscriptionCompletePayload {
-                task_id: task_id.to_string(),
-                audio_path: audio_path.to_string(),
-                transcript_path: temp_srt_path_str.clone(),
-            },
+    async fn fetch_result(
+        &self,
+        job_id: &str,
+        task_id: &str,
+        window: &Window,
+        app_handle: &AppHandle,
+        cancel_token: &CancellationToken,
+    ) -> Result<String> {
+        download_srt(
+            &self.backend_url,
+            job_id,
+            task_id,
+            window,
+            app_handle,
+            cancel_token,
         )
-        .context("Failed to emit transcription:complete event")?;
-
-    Ok(temp_srt_path_str)
+        .await
+    }
 }
@@ -2,11 +2,15 @@ use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
 use std::process::Stdio;
+use std::time::{Duration, Instant};
 use tauri::{AppHandle, Emitter, Manager, Window};
 use tokio::io::{AsyncBufReadExt, BufReader};
 use tokio::process::Command;
+use tokio_util::sync::CancellationToken;
 
+use crate::language::{iso639_2_code, language_matches};
 use crate::logger;
+use crate::task_registry::TaskCancelled;
 
 // Windows-specific imports for hiding console window
 // The CommandExt trait is required for the creation_flags method
@@ -18,11 +22,143 @@ use std::os::windows::process::CommandExt;
 #[cfg(target_os = "windows")]
 const CREATE_NO_WINDOW: u32 = 0x08000000;
 
+// Minimum time between two `task:progress` events for the same task
+const PROGRESS_EMIT_INTERVAL_MS: u64 = 500;
+
+// Audio quieter than SILENCE_NOISE_DB for at least SILENCE_MIN_SECS counts as a pause
+const SILENCE_NOISE_DB: i32 = -35;
+const SILENCE_MIN_SECS: f64 = 0.5;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TaskInfo {
     pub id: String,
     #[serde(rename = "filePath")]
     pub file_path: String,
+    /// Absolute index of the audio stream to extract (from `probe_media`)
+    #[serde(rename = "audioStreamIndex", default)]
+    pub audio_stream_index: Option<u32>,
+    /// Language tag of the audio stream to extract, e.g. "jpn"
+    #[serde(rename = "audioLanguage", default)]
+    pub audio_language: Option<String>,
+    /// Absolute index of the embedded subtitle stream to use instead of transcribing
+    #[serde(rename = "subtitleStreamIndex", default)]
+    pub subtitle_stream_index: Option<u32>,
+}
+
+/// Codec of the extracted audio. WAV is the largest; FLAC is lossless and about
+/// half the size, Opus is lossy but a fraction of it at speech bitrates.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum AudioCodec {
+    #[default]
+    Wav,
+    Flac,
+    Opus {
+        /// Target bitrate in kbit/s
+        #[serde(rename = "bitrateKbps", default = "default_opus_bitrate")]
+        bitrate_kbps: u32,
+    },
+}
+
+fn default_opus_bitrate() -> u32 {
+    32
+}
+
+impl AudioCodec {
+    /// Codec of an extracted audio file, from its extension; Opus gets `bitrate_kbps`
+    pub fn of_file(path: &Path, bitrate_kbps: u32) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("flac") => AudioCodec::Flac,
+            Some("ogg") | Some("opus") => AudioCodec::Opus { bitrate_kbps },
+            _ => AudioCodec::Wav,
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            AudioCodec::Wav => "wav",
+            AudioCodec::Flac => "flac",
+            AudioCodec::Opus { .. } => "ogg",
+        }
+    }
+
+    /// ffmpeg encoder arguments for 16 kHz mono speech
+    fn encoder_args(self) -> Vec<String> {
+        let mut args = match self {
+            AudioCodec::Wav => vec!["-acodec".to_string(), "pcm_s16le".to_string()],
+            AudioCodec::Flac => vec!["-acodec".to_string(), "flac".to_string()],
+            AudioCodec::Opus { bitrate_kbps } => vec![
+                "-acodec".to_string(),
+                "libopus".to_string(),
+                "-b:a".to_string(),
+                format!("{}k", bitrate_kbps.clamp(6, 256)),
+                "-application".to_string(),
+                "voip".to_string(),
+            ],
+        };
+        args.extend(
+            ["-ar", "16000", "-ac", "1"] // 16 kHz mono
+                .iter()
+                .map(|arg| arg.to_string()),
+        );
+        args
+    }
+}
+
+/// MIME type of an extracted audio file, from its extension
+pub fn audio_content_type(path: &Path) -> &'static str {
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("flac") => "audio/flac",
+        Some("ogg") | Some("opus") => "audio/ogg",
+        _ => "audio/wav",
+    }
+}
+
+/// Which audio stream to extract from a multi-track file.
+/// An explicit stream index wins; otherwise the first stream whose language
+/// matches is used, then the container's default stream, then the first one.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AudioTrackSelection {
+    #[serde(
+        rename = "audioStreamIndex",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub stream_index: Option<u32>,
+    #[serde(
+        rename = "audioLanguage",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub language: Option<String>,
+}
+
+impl AudioTrackSelection {
+    /// Pick the audio stream to extract from the probed streams
+    pub fn select<'a>(&self, media_info: &'a MediaInfo) -> Result<&'a AudioStreamInfo> {
+        let streams = &media_info.audio_streams;
+
+        if let Some(index) = self.stream_index {
+            return streams
+                .iter()
+                .find(|stream| stream.index == index)
+                .with_context(|| format!("Audio stream #{} not found in input file", index));
+        }
+
+        let by_language = self.language.as_deref().and_then(|wanted| {
+            streams.iter().find(|stream| {
+                stream
+                    .language
+                    .as_deref()
+                    .is_some_and(|tag| language_matches(tag, wanted))
+            })
+        });
+
+        by_language
+            .or_else(|| streams.iter().find(|stream| stream.is_default))
+            .or_else(|| streams.first())
+            .context("No audio stream found in input file")
+    }
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -31,6 +167,15 @@ pub struct TaskStartedPayload {
     pub task_id: String,
 }
 
+/// Progress of a long-running task stage, as a percentage (0-100)
+#[derive(Debug, Clone, Serialize)]
+pub struct TaskProgressPayload {
+    #[serde(rename = "taskId")]
+    pub task_id: String,
+    pub stage: String,
+    pub percent: f64,
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct TaskErrorPayload {
     #[serde(rename = "taskId")]
@@ -38,6 +183,224 @@ pub struct TaskErrorPayload {
     pub error: String,
 }
 
+/// Which embedded subtitle stream to extract.
+/// Only text-based streams are considered; bitmap subtitles (PGS, VobSub)
+/// cannot be converted to SRT without OCR.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SubtitleTrackSelection {
+    #[serde(
+        rename = "subtitleStreamIndex",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub stream_index: Option<u32>,
+    #[serde(
+        rename = "subtitleLanguage",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub language: Option<String>,
+}
+
+/// Subtitle codecs ffmpeg can convert to SRT
+const TEXT_SUBTITLE_CODECS: &[&str] =
+    &["subrip", "srt", "ass", "ssa", "mov_text", "webvtt", "text"];
+
+impl SubtitleStreamInfo {
+    pub fn is_text(&self) -> bool {
+        TEXT_SUBTITLE_CODECS.contains(&self.codec.as_str())
+    }
+}
+
+impl SubtitleTrackSelection {
+    /// Pick the text subtitle stream to extract, or None if the file has none
+    pub fn select<'a>(&self, media_info: &'a MediaInfo) -> Result<Option<&'a SubtitleStreamInfo>> {
+        let streams: Vec<&SubtitleStreamInfo> = media_info
+            .subtitle_streams
+            .iter()
+            .filter(|stream| stream.is_text())
+            .collect();
+
+        if let Some(index) = self.stream_index {
+            return streams
+                .into_iter()
+                .find(|stream| stream.index == index)
+                .with_context(|| format!("Text subtitle stream #{} not found in input file", index))
+                .map(Some);
+        }
+
+        let by_language = self.language.as_deref().and_then(|wanted| {
+            streams.iter().copied().find(|stream| {
+                stream
+                    .language
+                    .as_deref()
+                    .is_some_and(|tag| language_matches(tag, wanted))
+            })
+        });
+
+        Ok(by_language
+            .or_else(|| streams.iter().copied().find(|stream| stream.is_default))
+            .or_else(|| streams.first().copied()))
+    }
+}
+
+/// Audio stream found by ffprobe
+#[derive(Debug, Clone, Serialize)]
+pub struct AudioStreamInfo {
+    /// Absolute stream index within the container (usable as `-map 0:<index>`)
+    pub index: u32,
+    pub codec: String,
+    /// ISO 639 language tag, if the container declares one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    pub channels: u32,
+    #[serde(rename = "sampleRate")]
+    pub sample_rate: u32,
+    #[serde(rename = "isDefault")]
+    pub is_default: bool,
+}
+
+/// Embedded subtitle stream found by ffprobe
+#[derive(Debug, Clone, Serialize)]
+pub struct SubtitleStreamInfo {
+    /// Absolute stream index within the container (usable as `-map 0:<index>`)
+    pub index: u32,
+    pub codec: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(rename = "isDefault")]
+    pub is_default: bool,
+}
+
+/// Summary of a media file as reported by ffprobe
+#[derive(Debug, Clone, Serialize)]
+pub struct MediaInfo {
+    /// Duration in seconds, if the container reports one
+    #[serde(rename = "durationSecs", skip_serializing_if = "Option::is_none")]
+    pub duration_secs: Option<f64>,
+    /// Container format name(s), e.g. "matroska,webm" or "mov,mp4,m4a,3gp,3g2,mj2"
+    pub container: String,
+    #[serde(rename = "audioStreams")]
+    pub audio_streams: Vec<AudioStreamInfo>,
+    #[serde(rename = "subtitleStreams")]
+    pub subtitle_streams: Vec<SubtitleStreamInfo>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TaskMediaInfoPayload {
+    #[serde(rename = "taskId")]
+    pub task_id: String,
+    #[serde(rename = "mediaInfo")]
+    pub media_info: MediaInfo,
+}
+
+#[derive(Debug, Deserialize)]
+struct FfprobeOutput {
+    #[serde(default)]
+    streams: Vec<FfprobeStream>,
+    format: Option<FfprobeFormat>,
+}
+
+#[derive(Debug, Deserialize)]
+struct FfprobeStream {
+    index: u32,
+    codec_type: Option<String>,
+    codec_name: Option<String>,
+    channels: Option<u32>,
+    // ffprobe reports sample_rate and duration as strings
+    sample_rate: Option<String>,
+    duration: Option<String>,
+    #[serde(default)]
+    tags: FfprobeTags,
+    #[serde(default)]
+    disposition: FfprobeDisposition,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct FfprobeTags {
+    language: Option<String>,
+    title: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct FfprobeDisposition {
+    #[serde(default)]
+    default: u8,
+}
+
+#[derive(Debug, Deserialize)]
+struct FfprobeFormat {
+    format_name: Option<String>,
+    duration: Option<String>,
+}
+
+impl From<FfprobeOutput> for MediaInfo {
+    fn from(output: FfprobeOutput) -> Self {
+        let format = output.format;
+
+        // Prefer the container duration, falling back to the longest stream
+        let duration_secs = format
+            .as_ref()
+            .and_then(|format| format.duration.as_deref())
+            .and_then(|duration| duration.parse::<f64>().ok())
+            .or_else(|| {
+                output
+                    .streams
+                    .iter()
+                    .filter_map(|stream| stream.duration.as_deref()?.parse::<f64>().ok())
+                    .reduce(f64::max)
+            });
+
+        let container = format
+            .and_then(|format| format.format_name)
+            .unwrap_or_else(|| "unknown".to_string());
+
+        let mut audio_streams = Vec::new();
+        let mut subtitle_streams = Vec::new();
+
+        for stream in output.streams {
+            let codec = stream.codec_name.unwrap_or_else(|| "unknown".to_string());
+            // "und" is ffmpeg's marker for an undetermined language
+            let language = stream.tags.language.filter(|lang| lang != "und");
+            let is_default = stream.disposition.default == 1;
+
+            match stream.codec_type.as_deref() {
+                Some("audio") => audio_streams.push(AudioStreamInfo {
+                    index: stream.index,
+                    codec,
+                    language,
+                    title: stream.tags.title,
+                    channels: stream.channels.unwrap_or(0),
+                    sample_rate: stream
+                        .sample_rate
+                        .and_then(|rate| rate.parse().ok())
+                        .unwrap_or(0),
+                    is_default,
+                }),
+                Some("subtitle") => subtitle_streams.push(SubtitleStreamInfo {
+                    index: stream.index,
+                    codec,
+                    language,
+                    title: stream.tags.title,
+                    is_default,
+                }),
+                _ => {}
+            }
+        }
+
+        MediaInfo {
+            duration_secs,
+            container,
+            audio_streams,
+            subtitle_streams,
+        }
+    }
+}
+
 /// Get the path to a bundled binary, falling back to system PATH in dev mode
 fn get_binary_path(_app_handle: &AppHandle, binary_name: &str) -> Result<PathBuf> {
     // Debug builds run from the developer environment, so we rely on PATH there.
@@ -77,99 +440,214 @@ fn get_binary_path(_app_handle: &AppHandle, binary_name: &str) -> Result<PathBuf
     Ok(sidecar_path)
 }
 
-/// Extract audio from a video file to WAV format
-/// Returns the path to the extracted audio file in the temp directory
-pub async fn extract_audio_to_wav(
-    task_id: &str,
-    input_path: &str,
-    window: &Window,
+/// Inspect a media file with the bundled ffprobe
+pub async fn probe_media(app_handle: &AppHandle, input_path: &str) -> Result<MediaInfo> {
+    let ffprobe_path = get_binary_path(app_handle, "ffprobe")?;
+
+    let mut cmd = Command::new(ffprobe_path);
+    cmd.arg("-v")
+        .arg("error")
+        .arg("-print_format")
+        .arg("json")
+        .arg("-show_format")
+        .arg("-show_streams")
+        .arg(input_path)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true);
+
+    // On Windows, prevent console window from appearing
+    #[cfg(target_os = "windows")]
+    cmd.creation_flags(CREATE_NO_WINDOW);
+
+    let output = cmd.output().await.context("Failed to run ffprobe")?;
+
+    if !output.status.success() {
+        anyhow::bail!(
+            "FFprobe failed with status {}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    let probe_output: FfprobeOutput =
+        serde_json::from_slice(&output.stdout).context("Failed to parse ffprobe output")?;
+
+    Ok(probe_output.into())
+}
+
+/// Parse a `silencedetect` log line into ("start"/"end", seconds),
+/// e.g. `[silencedetect @ 0x...] silence_end: 12.48 | silence_duration: 0.74`
+fn parse_silence_line(line: &str) -> Option<(&'static str, f64)> {
+    for (key, kind) in [("silence_start:", "start"), ("silence_end:", "end")] {
+        if let Some((_, rest)) = line.split_once(key) {
+            let value = rest.split('|').next()?.trim().parse().ok()?;
+            return Some((kind, value));
+        }
+    }
+    None
+}
+
+/// Find the silent stretches of an audio file with ffmpeg's `silencedetect` filter
+/// Returns (start, end) pairs in seconds; a silence running to the end is closed at `duration_secs`
+pub async fn detect_silences(
     app_handle: &AppHandle,
-) -> Result<String> {
-    // Initialize task log
-    logger::init_task_log(app_handle, task_id)
-        .await
-        .context("Failed to initialize task log")?;
+    audio_path: &str,
+    duration_secs: f64,
+    cancel_token: &CancellationToken,
+) -> Result<Vec<(f64, f64)>> {
+    let ffmpeg_path = get_binary_path(app_handle, "ffmpeg")?;
 
-    // Log task metadata
-    logger::append_log_entry(
-        app_handle,
-        window,
-        task_id,
-        "metadata",
-        &format!("Starting audio extraction for: {}", input_path),
-    )
-    .await?;
+    let mut cmd = Command::new(ffmpeg_path);
+    cmd.arg("-nostats")
+        .arg("-i")
+        .arg(audio_path)
+        .arg("-af")
+        .arg(format!(
+            "silencedetect=noise={}dB:d={}",
+            SILENCE_NOISE_DB, SILENCE_MIN_SECS
+        ))
+        .arg("-f")
+        .arg("null")
+        .arg("-")
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true);
 
-    // Emit task started event
-    window
-        .emit(
-            "task:started",
-            TaskStartedPayload {
-                task_id: task_id.to_string(),
-            },
-        )
-        .context("Failed to emit task:started event")?;
+    // On Windows, prevent console window from appearing
+    #[cfg(target_os = "windows")]
+    cmd.creation_flags(CREATE_NO_WINDOW);
 
-    // Get the input file name without extension
-    let input_file = Path::new(input_path);
-    let file_stem = input_file
-        .file_stem()
-        .context("Failed to get file name")?
-        .to_str()
-        .context("Invalid file name")?;
+    // Dropping the future on cancellation kills the process
+    let output = tokio::select! {
+        output = cmd.output() => output.context("Failed to run ffmpeg silence detection")?,
+        _ = cancel_token.cancelled() => return Err(TaskCancelled.into()),
+    };
 
-    // Create output path in temp directory
-    let temp_dir = app_handle
-        .path()
-        .temp_dir()
-        .context("Failed to get temp directory")?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "FFmpeg silence detection failed with status: {}",
+            output.status
+        );
+    }
 
-    // Create a subdirectory for audio extraction
-    let audio_temp_dir = temp_dir.join("translation-app-audio");
-    std::fs::create_dir_all(&audio_temp_dir).context("Failed to create audio temp directory")?;
+    Ok(parse_silences(
+        &String::from_utf8_lossy(&output.stderr),
+        duration_secs,
+    ))
+}
 
-    let output_path = audio_temp_dir.join(format!("{}_{}.wav", task_id, file_stem));
-    let output_path_str = output_path
-        .to_str()
-        .context("Invalid output path")?
-        .to_string();
+/// Pair the `silence_start`/`silence_end` lines of a `silencedetect` log into (start, end)
+/// stretches; a silence still open at the end of the log is closed at `duration_secs`
+pub fn parse_silences(log: &str, duration_secs: f64) -> Vec<(f64, f64)> {
+    let mut silences = Vec::new();
+    let mut open_start = None;
+    for line in log.lines() {
+        match parse_silence_line(line) {
+            Some(("start", start)) => open_start = Some(start),
+            Some((_, end)) => {
+                if let Some(start) = open_start.take() {
+                    silences.push((start, end));
+                }
+            }
+            None => {}
+        }
+    }
+    if let Some(start) = open_start {
+        silences.push((start, duration_secs));
+    }
 
-    logger::append_log_entry(
-        app_handle,
-        window,
+    silences
+}
+
+/// Write `duration_secs` of audio starting at `start_secs` to `output_path` in `codec`.
+/// The cut is re-encoded: copying would start it at the packet boundary before
+/// `start_secs`, shifting every timestamp transcribed from it.
+#[allow(clippy::too_many_arguments)]
+pub async fn cut_audio(
+    audio_path: &str,
+    start_secs: f64,
+    duration_secs: f64,
+    codec: AudioCodec,
+    output_path: &str,
+    task_id: &str,
+    window: &Window,
+    app_handle: &AppHandle,
+    cancel_token: &CancellationToken,
+) -> Result<()> {
+    let mut args = vec![
+        "-ss".to_string(),
+        format!("{:.3}", start_secs),
+        "-i".to_string(),
+        audio_path.to_string(),
+        "-t".to_string(),
+        format!("{:.3}", duration_secs),
+    ];
+    args.extend(codec.encoder_args());
+    args.push("-y".to_string()); // Overwrite output file
+    args.push(output_path.to_string());
+
+    run_ffmpeg(
+        &args,
+        output_path,
+        "splitting",
+        Some(duration_secs),
+        None,
         task_id,
-        "metadata",
-        &format!("Extracting audio to temp file: {}", output_path_str),
+        window,
+        app_handle,
+        cancel_token,
     )
-    .await?;
+    .await
+}
 
+/// Parse the elapsed output time (in seconds) from an ffmpeg `-progress` line.
+/// Despite its name, `out_time_ms` is reported in microseconds.
+fn parse_progress_time_secs(line: &str) -> Option<f64> {
+    let (key, value) = line.split_once('=')?;
+    match key.trim() {
+        "out_time_ms" | "out_time_us" => {
+            let micros = value.trim().parse::<i64>().ok()?;
+            // ffmpeg reports a negative time before the first frame is written
+            (micros >= 0).then(|| micros as f64 / 1_000_000.0)
+        }
+        _ => None,
+    }
+}
+
+/// Run ffmpeg with the given arguments for a task.
+/// stderr is streamed into the task log, and `-progress pipe:1` output on stdout
+/// is turned into throttled `task:progress` events when the duration is known.
+/// Relative paths in `args` are resolved against `working_dir` when given.
+/// If the task is cancelled the process is killed and `output_path` is removed.
+#[allow(clippy::too_many_arguments)]
+async fn run_ffmpeg(
+    args: &[String],
+    output_path: &str,
+    stage: &str,
+    duration_secs: Option<f64>,
+    working_dir: Option<&Path>,
+    task_id: &str,
+    window: &Window,
+    app_handle: &AppHandle,
+    cancel_token: &CancellationToken,
+) -> Result<()> {
     // Get ffmpeg binary path
     let ffmpeg_path = get_binary_path(app_handle, "ffmpeg")?;
 
-    logger::append_log_entry(
-        app_handle,
-        window,
-        task_id,
-        "metadata",
-        "Starting ffmpeg extraction...",
-    )
-    .await?;
-
-    // Build ffmpeg command
+    // Build ffmpeg command, with machine-readable progress on stdout
     let mut cmd = Command::new(ffmpeg_path);
-    cmd.arg("-i")
-        .arg(input_path)
-        .arg("-vn") // No video
-        .arg("-acodec")
-        .arg("pcm_s16le") // WAV codec
-        .arg("-ar")
-        .arg("16000") // Sample rate
-        .arg("-ac")
-        .arg("1") // Mono
-        .arg("-y") // Overwrite output file
-        .arg(&output_path_str)
+    cmd.arg("-progress")
+        .arg("pipe:1")
+        .arg("-nostats")
+        .args(args)
         .stdout(Stdio::piped())
-        .stderr(Stdio::piped());
+        .stderr(Stdio::piped())
+        .kill_on_drop(true);
+
+    if let Some(dir) = working_dir {
+        cmd.current_dir(dir);
+    }
 
     // On Windows, prevent console window from appearing
     #[cfg(target_os = "windows")]
@@ -202,34 +680,85 @@ pub async fn extract_audio_to_wav(
         None
     };
 
-    // Read stdout if needed
+    // Read progress key=value pairs from stdout
     let stdout_handle = if let Some(stdout) = child.stdout.take() {
         let reader = BufReader::new(stdout);
         let mut lines = reader.lines();
         let window_clone = window.clone();
-        let app_handle_clone = app_handle.clone();
         let task_id_clone = task_id.to_string();
+        let stage = stage.to_string();
 
         Some(tokio::spawn(async move {
+            let mut last_emit: Option<Instant> = None;
+
             while let Ok(Some(line)) = lines.next_line().await {
-                let _ = logger::append_log_entry(
-                    &app_handle_clone,
-                    &window_clone,
-                    &task_id_clone,
-                    "ffmpeg",
-                    &line,
-                )
-                .await;
+                let Some(duration) = duration_secs.filter(|duration| *duration > 0.0) else {
+                    continue;
+                };
+
+                let percent = if line.trim() == "progress=end" {
+                    100.0
+                } else if let Some(elapsed) = parse_progress_time_secs(&line) {
+                    (elapsed / duration * 100.0).clamp(0.0, 100.0)
+                } else {
+                    continue;
+                };
+
+                // Throttle events so long files don't flood the frontend
+                let due = last_emit.is_none_or(|last| {
+                    last.elapsed() >= Duration::from_millis(PROGRESS_EMIT_INTERVAL_MS)
+                });
+                if !due && percent < 100.0 {
+                    continue;
+                }
+                last_emit = Some(Instant::now());
+
+                let _ = window_clone.emit(
+                    "task:progress",
+                    TaskProgressPayload {
+                        task_id: task_id_clone.clone(),
+                        stage: stage.clone(),
+                        percent: (percent * 10.0).round() / 10.0,
+                    },
+                );
             }
         }))
     } else {
         None
     };
 
-    // Wait for the process to complete
-    let output = child.wait().await.context("Failed to wait for ffmpeg")?;
+    // Wait for the process to complete, killing it if the task is cancelled
+    let output = tokio::select! {
+        status = child.wait() => status.context("Failed to wait for ffmpeg")?,
+        _ = cancel_token.cancelled() => {
+            let _ = child.kill().await;
+
+            // Stop the readers; the pipes close once the child is gone
+            if let Some(handle) = stderr_handle {
+                handle.abort();
+            }
+            if let Some(handle) = stdout_handle {
+                handle.abort();
+            }
+
+            // Remove the partially written output
+            let _ = tokio::fs::remove_file(output_path).await;
+
+            // A failed log write must not turn the cancellation into a failure
+            let _ = logger::append_log_entry(
+                app_handle,
+                window,
+                task_id,
+                "metadata",
+                &format!("FFmpeg {} cancelled, process killed", stage),
+            )
+            .await;
 
-    // Wait for log readers to complete before proceeding
+            return Err(TaskCancelled.into());
+        }
+    };
+
+    // Wait for readers to complete before proceeding
     if let Some(handle) = stderr_handle {
         let _ = handle.await;
     }
@@ -243,17 +772,809 @@ pub async fn extract_audio_to_wav(
         anyhow::bail!(error_msg);
     }
 
+    Ok(())
+}
+
+/// Start the log of a new video task, emit `task:started` and probe the input
+/// once for both the subtitle and the audio extraction that may follow
+pub async fn start_media_task(
+    task_id: &str,
+    input_path: &str,
+    window: &Window,
+    app_handle: &AppHandle,
+) -> Result<MediaInfo> {
+    logger::init_task_log(app_handle, task_id)
+        .await
+        .context("Failed to initialize task log")?;
+
+    // Emit task started event
+    window
+        .emit(
+            "task:started",
+            TaskStartedPayload {
+                task_id: task_id.to_string(),
+            },
+        )
+        .context("Failed to emit task:started event")?;
+
+    let media_info = probe_media(app_handle, input_path)
+        .await
+        .context("Failed to probe input file")?;
+
+    logger::append_log_entry(
+        app_handle,
+        window,
+        task_id,
+        "ffprobe",
+        &format!(
+            "Container: {}, duration: {}, audio streams: {}, subtitle streams: {}",
+            media_info.container,
+            media_info
+                .duration_secs
+                .map(|secs| format!("{:.1}s", secs))
+                .unwrap_or_else(|| "unknown".to_string()),
+            media_info.audio_streams.len(),
+            media_info.subtitle_streams.len()
+        ),
+    )
+    .await?;
+
+    let _ = window.emit(
+        "task:media-info",
+        TaskMediaInfoPayload {
+            task_id: task_id.to_string(),
+            media_info: media_info.clone(),
+        },
+    );
+
+    Ok(media_info)
+}
+
+/// Extract an embedded text subtitle stream to SRT, for translation without transcription
+/// Returns the path to the SRT file in the temp directory,
+/// or None if the input has no text subtitle stream to extract
+pub async fn extract_subtitle_to_srt(
+    task_id: &str,
+    input_path: &str,
+    subtitle_track: &SubtitleTrackSelection,
+    media_info: &MediaInfo,
+    window: &Window,
+    app_handle: &AppHandle,
+    cancel_token: &CancellationToken,
+) -> Result<Option<String>> {
     logger::append_log_entry(
         app_handle,
         window,
         task_id,
         "metadata",
-        "FFmpeg extraction completed successfully",
+        &format!("Looking for embedded subtitles in: {}", input_path),
     )
     .await?;
 
-    // Don't emit task:completed here - this is just audio extraction phase
-    // The task will be marked as completed after transcription finishes
+    for stream in &media_info.subtitle_streams {
+        logger::append_log_entry(
+            app_handle,
+            window,
+            task_id,
+            "ffprobe",
+            &format!(
+                "Subtitle stream #{}: {} ({}){}{}",
+                stream.index,
+                stream.codec,
+                stream.language.as_deref().unwrap_or("unknown language"),
+                if stream.is_default { ", default" } else { "" },
+                if stream.is_text() {
+                    ""
+                } else {
+                    ", bitmap - not extractable"
+                }
+            ),
+        )
+        .await?;
+    }
 
-    Ok(output_path_str)
+    let selected_stream = match subtitle_track.select(media_info) {
+        Ok(Some(stream)) => stream,
+        Ok(None) => {
+            logger::append_log_entry(
+                app_handle,
+                window,
+                task_id,
+                "metadata",
+                "No embedded text subtitle stream found",
+            )
+            .await?;
+            return Ok(None);
+        }
+        Err(e) => {
+            logger::append_log_entry(app_handle, window, task_id, "error", &e.to_string()).await?;
+            return Err(e);
+        }
+    };
+
+    // Get the input file name without extension
+    let file_stem = Path::new(input_path)
+        .file_stem()
+        .context("Failed to get file name")?
+        .to_str()
+        .context("Invalid file name")?;
+
+    // Save next to transcribed SRTs, with the same -original.srt suffix
+    let temp_dir = app_handle
+        .path()
+        .temp_dir()
+        .context("Failed to get temp directory")?;
+
+    let srt_temp_dir = temp_dir.join("translation-app-srt");
+    std::fs::create_dir_all(&srt_temp_dir).context("Failed to create SRT temp directory")?;
+
+    let output_path = srt_temp_dir.join(format!("{}_{}-original.srt", task_id, file_stem));
+    let output_path_str = output_path
+        .to_str()
+        .context("Invalid output path")?
+        .to_string();
+
+    logger::append_log_entry(
+        app_handle,
+        window,
+        task_id,
+        "metadata",
+        &format!(
+            "Extracting subtitle stream #{} ({}, {}) to: {}",
+            selected_stream.index,
+            selected_stream.codec,
+            selected_stream
+                .language
+                .as_deref()
+                .unwrap_or("unknown language"),
+            output_path_str
+        ),
+    )
+    .await?;
+
+    let args = [
+        "-i".to_string(),
+        input_path.to_string(),
+        "-map".to_string(),
+        format!("0:{}", selected_stream.index), // Selected subtitle stream only
+        "-c:s".to_string(),
+        "srt".to_string(), // Convert ASS/mov_text/WebVTT to SubRip
+        "-y".to_string(),  // Overwrite output file
+        output_path_str.clone(),
+    ];
+
+    run_ffmpeg(
+        &args,
+        &output_path_str,
+        "extracting",
+        media_info.duration_secs,
+        None,
+        task_id,
+        window,
+        app_handle,
+        cancel_token,
+    )
+    .await?;
+
+    logger::append_log_entry(
+        app_handle,
+        window,
+        task_id,
+        "metadata",
+        "Embedded subtitle extracted successfully, skipping transcription",
+    )
+    .await?;
+
+    Ok(Some(output_path_str))
+}
+
+/// A subtitle file to mux into a video as a soft subtitle track
+#[derive(Debug, Clone)]
+pub struct MuxSubtitleTrack {
+    pub srt_path: String,
+    /// Language tag or name, written as ISO 639-2 metadata ("und" if unknown)
+    pub language: Option<String>,
+    pub title: String,
+    /// Mark this track as the one players show by default
+    pub is_default: bool,
+}
+
+/// Payload for `mux:complete` and `hardsub:complete` events
+#[derive(Debug, Clone, Serialize)]
+pub struct ExportCompletePayload {
+    #[serde(rename = "taskId")]
+    pub task_id: String,
+    #[serde(rename = "outputPath")]
+    pub output_path: String,
+}
+
+/// Write a copy of the video with the given SRT files added as soft subtitle
+/// tracks, without re-encoding any existing stream.
+/// MP4/MOV keep their container (subtitles as mov_text); everything else is
+/// written as MKV. Returns the output path (`<stem>_<label>.<ext>`).
+#[allow(clippy::too_many_arguments)]
+pub async fn mux_subtitles(
+    task_id: &str,
+    video_path: &str,
+    tracks: &[MuxSubtitleTrack],
+    output_folder: &str,
+    output_label: &str,
+    window: &Window,
+    app_handle: &AppHandle,
+    cancel_token: &CancellationToken,
+) -> Result<String> {
+    let input_file = Path::new(video_path);
+    let file_stem = input_file
+        .file_stem()
+        .context("Failed to get file name")?
+        .to_str()
+        .context("Invalid file name")?;
+    let extension = input_file
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .unwrap_or_default()
+        .to_lowercase();
+
+    // MP4-family containers only accept mov_text subtitles; MKV takes SRT as-is
+    let (output_extension, subtitle_codec) = match extension.as_str() {
+        "mp4" | "m4v" | "mov" => (extension.as_str(), "mov_text"),
+        _ => ("mkv", "srt"),
+    };
+
+    let sanitized_label = output_label.replace(" ", "_");
+    let output_path = Path::new(output_folder).join(format!(
+        "{}_{}.{}",
+        file_stem, sanitized_label, output_extension
+    ));
+    let output_path_str = output_path
+        .to_str()
+        .context("Invalid mux output path")?
+        .to_string();
+
+    // New tracks are appended after the subtitle streams already in the file
+    let media_info = probe_media(app_handle, video_path)
+        .await
+        .context("Failed to probe input file")?;
+    let existing_subtitles = media_info.subtitle_streams.len();
+
+    logger::append_log_entry(
+        app_handle,
+        window,
+        task_id,
+        "metadata",
+        &format!(
+            "Muxing {} subtitle track(s) into: {}",
+            tracks.len(),
+            output_path_str
+        ),
+    )
+    .await?;
+
+    let mut args = vec!["-i".to_string(), video_path.to_string()];
+    for track in tracks {
+        args.push("-i".to_string());
+        args.push(track.srt_path.clone());
+    }
+
+    // Keep every original stream, then add each SRT input
+    args.push("-map".to_string());
+    args.push("0".to_string());
+    for input_index in 1..=tracks.len() {
+        args.push("-map".to_string());
+        args.push(format!("{}:0", input_index));
+    }
+
+    // Copy everything; only the new subtitle tracks are converted
+    args.push("-c".to_string());
+    args.push("copy".to_string());
+
+    // A new default track takes over from the file's own, so players don't see two defaults
+    if tracks.iter().any(|track| track.is_default) {
+        for stream in 0..existing_subtitles {
+            args.push(format!("-disposition:s:{}", stream));
+            args.push("0".to_string());
+        }
+    }
+
+    for (offset, track) in tracks.iter().enumerate() {
+        let stream = existing_subtitles + offset;
+        let language = track
+            .language
+            .as_deref()
+            .and_then(iso639_2_code)
+            .unwrap_or("und");
+
+        args.push(format!("-c:s:{}", stream));
+        args.push(subtitle_codec.to_string());
+        args.push(format!("-metadata:s:s:{}", stream));
+        args.push(format!("language={}", language));
+        args.push(format!("-metadata:s:s:{}", stream));
+        args.push(format!("title={}", track.title));
+        args.push(format!("-disposition:s:{}", stream));
+        args.push(if track.is_default { "default" } else { "0" }.to_string());
+    }
+
+    args.push("-y".to_string()); // Overwrite output file
+    args.push(output_path_str.clone());
+
+    run_ffmpeg(
+        &args,
+        &output_path_str,
+        "muxing",
+        media_info.duration_secs,
+        None,
+        task_id,
+        window,
+        app_handle,
+        cancel_token,
+    )
+    .await?;
+
+    logger::append_log_entry(
+        app_handle,
+        window,
+        task_id,
+        "metadata",
+        &format!("Subtitles muxed successfully: {}", output_path_str),
+    )
+    .await?;
+
+    window
+        .emit(
+            "mux:complete",
+            ExportCompletePayload {
+                task_id: task_id.to_string(),
+                output_path: output_path_str.clone(),
+            },
+        )
+        .context("Failed to emit mux:complete event")?;
+
+    Ok(output_path_str)
+}
+
+/// Vertical placement of burned-in subtitles
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SubtitlePosition {
+    #[default]
+    Bottom,
+    Middle,
+    Top,
+}
+
+impl SubtitlePosition {
+    /// ASS numpad alignment (bottom/middle/top center)
+    fn ass_alignment(self) -> u8 {
+        match self {
+            SubtitlePosition::Bottom => 2,
+            SubtitlePosition::Middle => 5,
+            SubtitlePosition::Top => 8,
+        }
+    }
+}
+
+fn default_font_name() -> String {
+    "Arial".to_string()
+}
+
+fn default_font_size() -> u32 {
+    24
+}
+
+fn default_outline() -> f32 {
+    2.0
+}
+
+fn default_margin_v() -> u32 {
+    20
+}
+
+/// Styling for subtitles burned into the picture (hardsub)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HardsubStyle {
+    #[serde(rename = "fontName", default = "default_font_name")]
+    pub font_name: String,
+    /// Font size in libass script units (video height is scaled to 288)
+    #[serde(rename = "fontSize", default = "default_font_size")]
+    pub font_size: u32,
+    /// Outline thickness in pixels
+    #[serde(default = "default_outline")]
+    pub outline: f32,
+    #[serde(default)]
+    pub position: SubtitlePosition,
+    /// Distance from the top/bottom edge
+    #[serde(rename = "marginV", default = "default_margin_v")]
+    pub margin_v: u32,
+}
+
+impl Default for HardsubStyle {
+    fn default() -> Self {
+        Self {
+            font_name: default_font_name(),
+            font_size: default_font_size(),
+            outline: default_outline(),
+            position: SubtitlePosition::default(),
+            margin_v: default_margin_v(),
+        }
+    }
+}
+
+impl HardsubStyle {
+    /// Build the `force_style` value for the subtitles filter
+    fn force_style(&self) -> String {
+        // Characters that would break out of the quoted filter argument
+        let font_name: String = self
+            .font_name
+            .chars()
+            .filter(|c| !matches!(c, '\'' | '\\' | ',' | ':' | '=' | ';' | '[' | ']'))
+            .collect();
+
+        format!(
+            "FontName={},FontSize={},Outline={},BorderStyle=1,Alignment={},MarginV={}",
+            font_name,
+            self.font_size,
+            self.outline,
+            self.position.ass_alignment(),
+            self.margin_v
+        )
+    }
+
+    /// Build the `subtitles` filter for a subtitle file in ffmpeg's working directory.
+    /// ASS/SSA scripts keep their own styles; the burn-in style only applies to SRT.
+    fn subtitles_filter(&self, file_name: &str) -> String {
+        if is_ass_script(file_name) {
+            format!("subtitles={}", file_name)
+        } else {
+            format!(
+                "subtitles={}:force_style='{}'",
+                file_name,
+                self.force_style()
+            )
+        }
+    }
+}
+
+fn is_ass_script(path: &str) -> bool {
+    Path::new(path)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("ass") || ext.eq_ignore_ascii_case("ssa"))
+}
+
+/// Render subtitles (SRT or an ASS script) into the video picture with the
+/// `subtitles` filter (CPU, libx264). Returns the output path (`<stem>_<label>_hardsub.mp4`)
+#[allow(clippy::too_many_arguments)]
+pub async fn burn_subtitles(
+    task_id: &str,
+    video_path: &str,
+    subtitle_path: &str,
+    style: &HardsubStyle,
+    audio_track: &AudioTrackSelection,
+    output_folder: &str,
+    output_label: &str,
+    window: &Window,
+    app_handle: &AppHandle,
+    cancel_token: &CancellationToken,
+) -> Result<String> {
+    let file_stem = Path::new(video_path)
+        .file_stem()
+        .context("Failed to get file name")?
+        .to_str()
+        .context("Invalid file name")?;
+
+    let sanitized_label = output_label.replace(" ", "_");
+    let output_path =
+        Path::new(output_folder).join(format!("{}_{}_hardsub.mp4", file_stem, sanitized_label));
+    let output_path_str = output_path
+        .to_str()
+        .context("Invalid hardsub output path")?
+        .to_string();
+
+    let media_info = probe_media(app_handle, video_path)
+        .await
+        .context("Failed to probe input file")?;
+
+    // The subtitles filter takes a path inside a filtergraph, where drive letters,
+    // quotes and commas all need escaping. Copy the file to a temp dir under a plain
+    // name and run ffmpeg from there so the filter only sees a relative file name.
+    let temp_dir = app_handle
+        .path()
+        .temp_dir()
+        .context("Failed to get temp directory")?;
+    let hardsub_temp_dir = temp_dir.join("translation-app-hardsub");
+    std::fs::create_dir_all(&hardsub_temp_dir)
+        .context("Failed to create hardsub temp directory")?;
+
+    // Keep the extension so libass reads ASS scripts as such
+    let subtitle_extension = Path::new(subtitle_path)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .unwrap_or("srt");
+    let filter_file_name = format!("{}.{}", task_id, subtitle_extension);
+    let filter_file_path = hardsub_temp_dir.join(&filter_file_name);
+    tokio::fs::copy(subtitle_path, &filter_file_path)
+        .await
+        .context("Failed to copy subtitles for burn-in")?;
+
+    logger::append_log_entry(
+        app_handle,
+        window,
+        task_id,
+        "metadata",
+        &if is_ass_script(subtitle_path) {
+            format!(
+                "Burning subtitles into video (styles from the ASS script): {}",
+                output_path_str
+            )
+        } else {
+            format!(
+                "Burning subtitles into video (font: {} {}, outline: {}, position: {:?}): {}",
+                style.font_name, style.font_size, style.outline, style.position, output_path_str
+            )
+        },
+    )
+    .await?;
+
+    let mut args = vec![
+        "-i".to_string(),
+        video_path.to_string(),
+        "-map".to_string(),
+        "0:v:0".to_string(),
+    ];
+
+    // Keep a single audio track, following the same selection as extraction
+    if let Ok(stream) = audio_track.select(&media_info) {
+        args.push("-map".to_string());
+        args.push(format!("0:{}", stream.index));
+    }
+
+    args.extend([
+        "-vf".to_string(),
+        style.subtitles_filter(&filter_file_name),
+        "-c:v".to_string(),
+        "libx264".to_string(),
+        "-preset".to_string(),
+        "medium".to_string(),
+        "-crf".to_string(),
+        "20".to_string(),
+        "-pix_fmt".to_string(),
+        "yuv420p".to_string(), // Widest player compatibility
+        "-c:a".to_string(),
+        "aac".to_string(),
+        "-b:a".to_string(),
+        "192k".to_string(),
+        "-movflags".to_string(),
+        "+faststart".to_string(), // Allow playback before the whole file is downloaded
+        "-y".to_string(),         // Overwrite output file
+        output_path_str.clone(),
+    ]);
+
+    let render_result = run_ffmpeg(
+        &args,
+        &output_path_str,
+        "burning",
+        media_info.duration_secs,
+        Some(&hardsub_temp_dir),
+        task_id,
+        window,
+        app_handle,
+        cancel_token,
+    )
+    .await;
+
+    let _ = tokio::fs::remove_file(&filter_file_path).await;
+    render_result?;
+
+    logger::append_log_entry(
+        app_handle,
+        window,
+        task_id,
+        "metadata",
+        &format!("Subtitles burned in successfully: {}", output_path_str),
+    )
+    .await?;
+
+    window
+        .emit(
+            "hardsub:complete",
+            ExportCompletePayload {
+                task_id: task_id.to_string(),
+                output_path: output_path_str.clone(),
+            },
+        )
+        .context("Failed to emit hardsub:complete event")?;
+
+    Ok(output_path_str)
+}
+
+/// Extract audio from a video file as 16 kHz mono in the given codec
+/// Returns the path to the extracted audio file in the temp directory
+#[allow(clippy::too_many_arguments)]
+pub async fn extract_audio(
+    task_id: &str,
+    input_path: &str,
+    audio_track: &AudioTrackSelection,
+    codec: AudioCodec,
+    media_info: &MediaInfo,
+    window: &Window,
+    app_handle: &AppHandle,
+    cancel_token: &CancellationToken,
+) -> Result<String> {
+    // Log task metadata
+    logger::append_log_entry(
+        app_handle,
+        window,
+        task_id,
+        "metadata",
+        &format!("Starting audio extraction for: {}", input_path),
+    )
+    .await?;
+
+    for stream in &media_info.audio_streams {
+        logger::append_log_entry(
+            app_handle,
+            window,
+            task_id,
+            "ffprobe",
+            &format!(
+                "Audio stream #{}: {} ({}), {} ch, {} Hz{}",
+                stream.index,
+                stream.codec,
+                stream.language.as_deref().unwrap_or("unknown language"),
+                stream.channels,
+                stream.sample_rate,
+                if stream.is_default { ", default" } else { "" }
+            ),
+        )
+        .await?;
+    }
+
+    if media_info.audio_streams.is_empty() {
+        let error_msg = format!("No audio stream found in {}", input_path);
+        logger::append_log_entry(app_handle, window, task_id, "error", &error_msg).await?;
+        anyhow::bail!(error_msg);
+    }
+
+    let selected_stream = match audio_track.select(media_info) {
+        Ok(stream) => stream,
+        Err(e) => {
+            logger::append_log_entry(app_handle, window, task_id, "error", &e.to_string()).await?;
+            return Err(e);
+        }
+    };
+
+    if let Some(wanted) = audio_track.language.as_deref() {
+        let matched = selected_stream
+            .language
+            .as_deref()
+            .is_some_and(|tag| language_matches(tag, wanted));
+        if !matched && audio_track.stream_index.is_none() {
+            logger::append_log_entry(
+                app_handle,
+                window,
+                task_id,
+                "metadata",
+                &format!(
+                    "Warning: No audio stream tagged '{}', using the default stream instead",
+                    wanted
+                ),
+            )
+            .await?;
+        }
+    }
+
+    logger::append_log_entry(
+        app_handle,
+        window,
+        task_id,
+        "metadata",
+        &format!(
+            "Selected audio stream #{} ({})",
+            selected_stream.index,
+            selected_stream
+                .language
+                .as_deref()
+                .unwrap_or("unknown language")
+        ),
+    )
+    .await?;
+
+    // Get the input file name without extension
+    let input_file = Path::new(input_path);
+    let file_stem = input_file
+        .file_stem()
+        .context("Failed to get file name")?
+        .to_str()
+        .context("Invalid file name")?;
+
+    // Create output path in temp directory
+    let temp_dir = app_handle
+        .path()
+        .temp_dir()
+        .context("Failed to get temp directory")?;
+
+    // Create a subdirectory for audio extraction
+    let audio_temp_dir = temp_dir.join("translation-app-audio");
+    std::fs::create_dir_all(&audio_temp_dir).context("Failed to create audio temp directory")?;
+
+    let output_path =
+        audio_temp_dir.join(format!("{}_{}.{}", task_id, file_stem, codec.extension()));
+    let output_path_str = output_path
+        .to_str()
+        .context("Invalid output path")?
+        .to_string();
+
+    logger::append_log_entry(
+        app_handle,
+        window,
+        task_id,
+        "metadata",
+        &format!("Extracting audio to temp file: {}", output_path_str),
+    )
+    .await?;
+
+    logger::append_log_entry(
+        app_handle,
+        window,
+        task_id,
+        "metadata",
+        "Starting ffmpeg extraction...",
+    )
+    .await?;
+
+    // Build ffmpeg arguments
+    let mut args = vec![
+        "-i".to_string(),
+        input_path.to_string(),
+        "-map".to_string(),
+        format!("0:{}", selected_stream.index), // Selected audio stream only
+        "-vn".to_string(),                      // No video
+    ];
+    args.extend(codec.encoder_args());
+    args.push("-y".to_string()); // Overwrite output file
+    args.push(output_path_str.clone());
+
+    run_ffmpeg(
+        &args,
+        &output_path_str,
+        "extracting",
+        media_info.duration_secs,
+        None,
+        task_id,
+        window,
+        app_handle,
+        cancel_token,
+    )
+    .await?;
+
+    logger::append_log_entry(
+        app_handle,
+        window,
+        task_id,
+        "metadata",
+        "FFmpeg extraction completed successfully",
+    )
+    .await?;
+
+    // Don't emit task:completed here - this is just audio extraction phase
+    // The task will be marked as completed after transcription finishes
+
+    Ok(output_path_str)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn forces_the_burn_in_style_on_srt_only() {
+        let style = HardsubStyle::default();
+
+        assert_eq!(
+            style.subtitles_filter("task.srt"),
+            format!("subtitles=task.srt:force_style='{}'", style.force_style())
+        );
+        assert_eq!(style.subtitles_filter("task.ass"), "subtitles=task.ass");
+        assert_eq!(style.subtitles_filter("task.SSA"), "subtitles=task.SSA");
+    }
 }
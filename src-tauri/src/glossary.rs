@@ -0,0 +1,490 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Manager};
+use tokio::fs;
+
+use crate::subtitle::{Cue, SubtitleDocument};
+
+/// One required translation of a term
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GlossaryEntry {
+    /// Term as it appears in the original subtitles
+    pub source: String,
+    /// Term the translation must contain wherever `source` appears
+    pub target: String,
+    /// Target language the entry applies to; `None` applies to every language
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+}
+
+/// Terminology of one project (e.g. a series), stored in the app data directory
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Glossary {
+    pub project: String,
+    pub entries: Vec<GlossaryEntry>,
+    #[serde(rename = "updatedAt")]
+    pub updated_at: String,
+}
+
+impl Glossary {
+    /// Entries that apply when translating into `language`
+    pub fn terms_for(&self, language: &str) -> Vec<GlossaryEntry> {
+        self.entries
+            .iter()
+            .filter(|entry| {
+                entry
+                    .language
+                    .as_deref()
+                    .is_none_or(|entry_language| entry_language.eq_ignore_ascii_case(language))
+            })
+            .cloned()
+            .collect()
+    }
+}
+
+/// A translated cue that is missing a glossary term
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GlossaryIssue {
+    /// 1-based cue number in the original subtitles
+    pub cue: usize,
+    #[serde(rename = "startMs")]
+    pub start_ms: u64,
+    pub source: String,
+    pub expected: String,
+    #[serde(rename = "originalText")]
+    pub original_text: String,
+    #[serde(rename = "translatedText")]
+    pub translated_text: String,
+}
+
+/// Get the glossaries directory path (sibling of the logs directory)
+async fn get_glossaries_dir(app_handle: &AppHandle) -> Result<PathBuf> {
+    let app_data_dir = app_handle
+        .path()
+        .app_data_dir()
+        .context("Failed to get app data directory")?;
+
+    let glossaries_dir = app_data_dir.join("glossaries");
+
+    if !glossaries_dir.exists() {
+        fs::create_dir_all(&glossaries_dir)
+            .await
+            .context("Failed to create glossaries directory")?;
+    }
+
+    Ok(glossaries_dir)
+}
+
+/// Get the glossary file path for a project (names are sanitized for the file system,
+/// so different names can share a path; the project name stored in the file tells them apart)
+async fn get_glossary_path(app_handle: &AppHandle, project: &str) -> Result<PathBuf> {
+    let file_name: String = project
+        .trim()
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if file_name.is_empty() {
+        anyhow::bail!("Glossary project name is empty");
+    }
+
+    let glossaries_dir = get_glossaries_dir(app_handle).await?;
+    Ok(glossaries_dir.join(format!("{}.json", file_name)))
+}
+
+/// Import a CSV or TSV file as a project's glossary, replacing its previous entries
+/// Columns: source term, target term, and an optional target language
+pub async fn import_glossary(
+    app_handle: &AppHandle,
+    project: &str,
+    file_path: &str,
+) -> Result<Glossary> {
+    let content = fs::read_to_string(file_path)
+        .await
+        .with_context(|| format!("Failed to read glossary file: {}", file_path))?;
+    let entries = parse(&content, delimiter_for(Path::new(file_path), &content))?;
+
+    let glossary = Glossary {
+        project: project.trim().to_string(),
+        entries,
+        updated_at: chrono::Utc::now().to_rfc3339(),
+    };
+    save_glossary(app_handle, &glossary).await?;
+
+    Ok(glossary)
+}
+
+async fn save_glossary(app_handle: &AppHandle, glossary: &Glossary) -> Result<()> {
+    let glossary_path = get_glossary_path(app_handle, &glossary.project).await?;
+    if let Some(existing) = read_glossary_file(&glossary_path).await? {
+        if existing.project != glossary.project {
+            anyhow::bail!(
+                "Glossary name '{}' is too similar to the existing glossary '{}'",
+                glossary.project,
+                existing.project
+            );
+        }
+    }
+
+    let json = serde_json::to_string_pretty(glossary).context("Failed to serialize glossary")?;
+
+    fs::write(&glossary_path, json)
+        .await
+        .context("Failed to write glossary")
+}
+
+async fn read_glossary_file(glossary_path: &Path) -> Result<Option<Glossary>> {
+    if !glossary_path.exists() {
+        return Ok(None);
+    }
+
+    let content = fs::read_to_string(glossary_path)
+        .await
+        .context("Failed to read glossary")?;
+    let glossary = serde_json::from_str(&content).context("Failed to parse glossary")?;
+
+    Ok(Some(glossary))
+}
+
+/// Read a project's glossary
+pub async fn load_glossary(app_handle: &AppHandle, project: &str) -> Result<Option<Glossary>> {
+    let glossary_path = get_glossary_path(app_handle, project).await?;
+    let glossary = read_glossary_file(&glossary_path).await?;
+
+    // The file may belong to another project whose name sanitizes the same way
+    Ok(glossary.filter(|glossary| glossary.project == project.trim()))
+}
+
+/// Read every stored glossary, skipping files that cannot be parsed
+pub async fn load_all_glossaries(app_handle: &AppHandle) -> Result<Vec<Glossary>> {
+    let glossaries_dir = get_glossaries_dir(app_handle).await?;
+
+    let mut entries = fs::read_dir(&glossaries_dir)
+        .await
+        .context("Failed to read glossaries directory")?;
+
+    let mut glossaries = Vec::new();
+    while let Some(entry) = entries
+        .next_entry()
+        .await
+        .context("Failed to read glossaries directory entry")?
+    {
+        let path = entry.path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+            continue;
+        }
+
+        let parsed = fs::read_to_string(&path)
+            .await
+            .map_err(anyhow::Error::from)
+            .and_then(|content| Ok(serde_json::from_str::<Glossary>(&content)?));
+        match parsed {
+            Ok(glossary) => glossaries.push(glossary),
+            Err(e) => eprintln!("Failed to load glossary {}: {}", path.display(), e),
+        }
+    }
+
+    glossaries.sort_by(|a, b| a.project.cmp(&b.project));
+
+    Ok(glossaries)
+}
+
+/// Remove a project's glossary
+pub async fn delete_glossary(app_handle: &AppHandle, project: &str) -> Result<()> {
+    let glossary_path = get_glossary_path(app_handle, project).await?;
+    if load_glossary(app_handle, project).await?.is_none() {
+        return Ok(());
+    }
+
+    match fs::remove_file(&glossary_path).await {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(e).context("Failed to remove glossary"),
+    }
+}
+
+/// Tab for .tsv/.tab files or tab-separated content, comma otherwise
+fn delimiter_for(path: &Path, content: &str) -> char {
+    let extension = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.to_ascii_lowercase());
+    match extension.as_deref() {
+        Some("tsv") | Some("tab") => '\t',
+        Some("csv") => ',',
+        _ if content
+            .lines()
+            .next()
+            .is_some_and(|line| line.contains('\t')) =>
+        {
+            '\t'
+        }
+        _ => ',',
+    }
+}
+
+/// Split one delimited line into fields; double quotes allow delimiters inside a field
+/// and `""` inside quotes is a literal quote
+fn split_fields(line: &str, delimiter: char) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => in_quotes = !in_quotes,
+            c if c == delimiter && !in_quotes => fields.push(std::mem::take(&mut field)),
+            c => field.push(c),
+        }
+    }
+    fields.push(field);
+
+    fields.into_iter().map(|f| f.trim().to_string()).collect()
+}
+
+/// Parse glossary rows (`source, target[, language]`)
+/// Blank lines, `#` comments and a `source,target[,language]` header row are skipped
+pub fn parse(content: &str, delimiter: char) -> Result<Vec<GlossaryEntry>> {
+    let mut entries = Vec::new();
+
+    for (line_index, line) in content.trim_start_matches('\u{feff}').lines().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+
+        let fields = split_fields(trimmed, delimiter);
+        let is_header = fields[0].eq_ignore_ascii_case("source")
+            && fields
+                .get(1)
+                .is_some_and(|field| field.eq_ignore_ascii_case("target"));
+        if line_index == 0 && is_header {
+            continue;
+        }
+        if fields.len() < 2 || fields[0].is_empty() || fields[1].is_empty() {
+            anyhow::bail!(
+                "Glossary line {}: expected a source and a target term",
+                line_index + 1
+            );
+        }
+
+        entries.push(GlossaryEntry {
+            source: fields[0].clone(),
+            target: fields[1].clone(),
+            language: fields
+                .get(2)
+                .filter(|language| !language.is_empty())
+                .cloned(),
+        });
+    }
+
+    Ok(entries)
+}
+
+/// Whether `text` contains `term`, ignoring case. A term edge that is an ASCII letter or
+/// digit must not continue into another one (so "Al" doesn't match "Also").
+fn contains_term(text: &str, term: &str) -> bool {
+    let text = text.to_lowercase();
+    let term = term.to_lowercase();
+    if term.is_empty() {
+        return false;
+    }
+
+    let is_word = |c: char| c.is_ascii_alphanumeric();
+    let starts_word = term.chars().next().is_some_and(is_word);
+    let ends_word = term.chars().last().is_some_and(is_word);
+
+    text.match_indices(&term).any(|(start, _)| {
+        let end = start + term.len();
+        let before_ok = !starts_word || !text[..start].chars().last().is_some_and(is_word);
+        let after_ok = !ends_word || !text[end..].chars().next().is_some_and(is_word);
+        before_ok && after_ok
+    })
+}
+
+/// Entries whose source term appears in any of `cues`
+pub fn terms_in(cues: &[Cue], terms: &[GlossaryEntry]) -> Vec<GlossaryEntry> {
+    terms
+        .iter()
+        .filter(|entry| {
+            cues.iter()
+                .any(|cue| contains_term(&cue.text, &entry.source))
+        })
+        .cloned()
+        .collect()
+}
+
+/// Stable fingerprint of a set of terms, independent of their order; empty for no terms
+/// Translations remembered under one glossary are not reused once its terms change
+pub fn fingerprint(terms: &[GlossaryEntry]) -> String {
+    if terms.is_empty() {
+        return String::new();
+    }
+
+    let mut pairs: Vec<(&str, &str)> = terms
+        .iter()
+        .map(|entry| (entry.source.as_str(), entry.target.as_str()))
+        .collect();
+    pairs.sort_unstable();
+    pairs.dedup();
+
+    // 64-bit FNV-1a; std's hashers may change between Rust releases
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for (source, target) in pairs {
+        for byte in source
+            .bytes()
+            .chain([0x1f])
+            .chain(target.bytes())
+            .chain([0x1e])
+        {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+    format!("{:016x}", hash)
+}
+
+/// Check every original cue containing a glossary term for the required target term
+/// Cues are paired by position when the counts match, otherwise by start time
+pub fn check(
+    original: &SubtitleDocument,
+    translated: &SubtitleDocument,
+    terms: &[GlossaryEntry],
+) -> Vec<GlossaryIssue> {
+    let same_length = original.len() == translated.len();
+    let mut issues = Vec::new();
+
+    for (index, cue) in original.cues.iter().enumerate() {
+        let translated_cue = if same_length {
+            translated.cues.get(index)
+        } else {
+            translated
+                .cues
+                .iter()
+                .find(|translated_cue| translated_cue.start_ms == cue.start_ms)
+        };
+        let translated_text = translated_cue
+            .map(|translated_cue| translated_cue.text.as_str())
+            .unwrap_or_default();
+
+        for entry in terms {
+            if contains_term(&cue.text, &entry.source)
+                && !translated_text
+                    .to_lowercase()
+                    .contains(&entry.target.to_lowercase())
+            {
+                issues.push(GlossaryIssue {
+                    cue: index + 1,
+                    start_ms: cue.start_ms,
+                    source: entry.source.clone(),
+                    expected: entry.target.clone(),
+                    original_text: cue.text.clone(),
+                    translated_text: translated_text.to_string(),
+                });
+            }
+        }
+    }
+
+    issues
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Render issues as a CSV QA report
+pub fn report_to_csv(issues: &[GlossaryIssue]) -> String {
+    let mut output = String::from("cue,start,source_term,expected_term,original,translation\n");
+    for issue in issues {
+        let row = [
+            issue.cue.to_string(),
+            crate::subtitle::srt::format_timestamp(issue.start_ms),
+            csv_field(&issue.source),
+            csv_field(&issue.expected),
+            csv_field(&issue.original_text),
+            csv_field(&issue.translated_text),
+        ];
+        output.push_str(&row.join(","));
+        output.push('\n');
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(source: &str, target: &str) -> GlossaryEntry {
+        GlossaryEntry {
+            source: source.to_string(),
+            target: target.to_string(),
+            language: None,
+        }
+    }
+
+    #[test]
+    fn parses_csv_with_header_quotes_and_language() {
+        let content = "source,target,language\n\"Acme, Inc.\",Acme\nAlice,アリス,Japanese\n";
+        let entries = parse(content, ',').unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0], entry("Acme, Inc.", "Acme"));
+        assert_eq!(entries[1].language.as_deref(), Some("Japanese"));
+    }
+
+    #[test]
+    fn rejects_rows_without_target() {
+        assert!(parse("Alice\n", '\t').is_err());
+    }
+
+    #[test]
+    fn keeps_first_row_that_only_starts_with_source() {
+        let entries = parse("Source,ソース\nTarget,ターゲット\n", ',').unwrap();
+        assert_eq!(entries[0], entry("Source", "ソース"));
+    }
+
+    #[test]
+    fn matches_whole_ascii_words_only() {
+        assert!(contains_term("Hello, al!", "Al"));
+        assert!(!contains_term("Also here", "Al"));
+        assert!(contains_term("アリスです", "アリス"));
+    }
+
+    #[test]
+    fn flags_cues_missing_target_term() {
+        let original = SubtitleDocument::new(vec![
+            Cue::new(0, 1000, "Alice is here"),
+            Cue::new(1000, 2000, "Where is Alice?"),
+        ]);
+        let translated = SubtitleDocument::new(vec![
+            Cue::new(0, 1000, "アリスはここにいる"),
+            Cue::new(1000, 2000, "アリサはどこ？"),
+        ]);
+        let issues = check(&original, &translated, &[entry("Alice", "アリス")]);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].cue, 2);
+    }
+
+    #[test]
+    fn fingerprint_ignores_term_order() {
+        let terms = [entry("Alice", "アリス"), entry("Bob", "ボブ")];
+        let reversed = [terms[1].clone(), terms[0].clone()];
+        assert_eq!(fingerprint(&terms), fingerprint(&reversed));
+        assert_ne!(fingerprint(&terms), fingerprint(&terms[..1]));
+        assert_eq!(fingerprint(&[]), "");
+    }
+}
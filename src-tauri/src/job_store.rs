@@ -0,0 +1,340 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use tauri::{AppHandle, Manager};
+use tokio::fs;
+
+use crate::ffmpeg::{AudioCodec, AudioTrackSelection, HardsubStyle, SubtitleTrackSelection};
+use crate::subtitle::OutputOptions;
+use crate::target_languages::TargetLanguages;
+use crate::transcription::TranscriptionProviderConfig;
+use crate::translation_provider::TranslationOptions;
+use crate::translation_status::TranslationStatus;
+
+/// Pipeline stage a persisted job had reached when it was last saved
+/// Variants are declared in pipeline order, so stages can be compared
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum JobStage {
+    /// Waiting for a free processing slot
+    Queued,
+    /// ffmpeg is extracting audio to the temp directory
+    Extracting,
+    /// Audio extracted, upload to the transcription backend in progress
+    Uploading,
+    /// Backend job created, waiting for the transcript
+    Transcribing,
+    /// Original SRT downloaded, translation in progress
+    Translating,
+    /// Translated SRT written, muxing or burning it into the video
+    Exporting,
+}
+
+/// Where the original-language SRT for a video comes from
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SubtitleSource {
+    /// Extract audio and run a backend transcription
+    #[default]
+    Transcribe,
+    /// Extract an embedded text subtitle stream; fail if there is none
+    Embedded,
+    /// Use an embedded text subtitle stream if present, otherwise transcribe
+    Auto,
+}
+
+/// Per-batch options for the video pipeline, persisted with each job
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct JobOptions {
+    /// Preferred audio language for multi-track files, e.g. "jpn"
+    #[serde(
+        rename = "preferredAudioLanguage",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub preferred_audio_language: Option<String>,
+    #[serde(rename = "subtitleSource", default)]
+    pub subtitle_source: SubtitleSource,
+    /// Where the audio is transcribed (the backend or a local whisper.cpp)
+    #[serde(rename = "transcriptionProvider", default)]
+    pub transcription_provider: TranscriptionProviderConfig,
+    /// Codec of the audio extracted for transcription
+    #[serde(rename = "audioCodec", default)]
+    pub audio_codec: AudioCodec,
+    /// Preferred language of the embedded subtitle stream to extract
+    #[serde(
+        rename = "preferredSubtitleLanguage",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub preferred_subtitle_language: Option<String>,
+    /// Subtitle files to write for the translation (formats, bilingual mode)
+    #[serde(flatten)]
+    pub output: OutputOptions,
+    /// Source language, country and model hints for the translation server
+    #[serde(flatten)]
+    pub translation: TranslationOptions,
+    /// Write a copy of the video with the translated SRT muxed in as a soft subtitle
+    #[serde(rename = "muxSubtitles", default)]
+    pub mux_subtitles: bool,
+    /// Also mux the original-language SRT as a second subtitle track
+    #[serde(rename = "muxIncludeOriginal", default)]
+    pub mux_include_original: bool,
+    /// Render the translated SRT into the picture as `<stem>_<lang>_hardsub.mp4`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hardsub: Option<HardsubStyle>,
+}
+
+/// A resumable upload that was started but has not reached the end of the file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PendingUpload {
+    /// Upload resource on the backend
+    pub url: String,
+    /// Bytes the backend had confirmed when the record was last saved
+    pub offset: u64,
+    /// Size of the audio file, to detect a file that changed since
+    pub size: u64,
+}
+
+/// Persisted state of a video task, used to resume work after an app restart
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobRecord {
+    #[serde(rename = "taskId")]
+    pub task_id: String,
+    #[serde(rename = "filePath")]
+    pub file_path: String,
+    #[serde(rename = "outputFolder")]
+    pub output_folder: String,
+    #[serde(rename = "backendUrl")]
+    pub backend_url: String,
+    /// Languages to translate into; the first one is muxed as default and burned in.
+    /// Records written before multi-language support stored a single `targetLanguage`.
+    #[serde(
+        rename = "targetLanguages",
+        alias = "targetLanguage",
+        deserialize_with = "deserialize_target_languages"
+    )]
+    pub target_languages: Vec<String>,
+    #[serde(default)]
+    pub options: JobOptions,
+    /// Audio stream to extract from multi-track files
+    #[serde(flatten)]
+    pub audio_track: AudioTrackSelection,
+    /// Embedded subtitle stream to extract instead of transcribing
+    #[serde(flatten)]
+    pub subtitle_track: SubtitleTrackSelection,
+    pub stage: JobStage,
+    /// Temp WAV produced by the extraction stage
+    #[serde(rename = "audioPath", skip_serializing_if = "Option::is_none")]
+    pub audio_path: Option<String>,
+    /// Temp original SRT downloaded from the backend
+    #[serde(rename = "originalSrtPath", skip_serializing_if = "Option::is_none")]
+    pub original_srt_path: Option<String>,
+    /// Language the transcription backend detected in the audio
+    #[serde(
+        rename = "detectedLanguage",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub detected_language: Option<String>,
+    /// Translated SRT in the output folder per target language, set as each finishes
+    #[serde(
+        rename = "translatedSrtPaths",
+        default,
+        skip_serializing_if = "BTreeMap::is_empty"
+    )]
+    pub translated_srt_paths: BTreeMap<String, String>,
+    /// Status of each language in `translated_srt_paths`; missing in older records,
+    /// whose finished languages are taken as translated
+    #[serde(
+        rename = "translationStatuses",
+        default,
+        skip_serializing_if = "BTreeMap::is_empty"
+    )]
+    pub translation_statuses: BTreeMap<String, TranslationStatus>,
+    /// Backend transcription job ID, set once the upload has succeeded
+    #[serde(rename = "jobId", skip_serializing_if = "Option::is_none")]
+    pub job_id: Option<String>,
+    /// Unfinished resumable uploads by audio file path
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub uploads: BTreeMap<String, PendingUpload>,
+    /// Error of the last run; a failed job is kept until it is resumed or dismissed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(rename = "updatedAt")]
+    pub updated_at: String,
+}
+
+impl JobRecord {
+    pub fn new(
+        task_id: &str,
+        file_path: &str,
+        output_folder: &str,
+        backend_url: &str,
+        target_languages: &[String],
+        options: JobOptions,
+    ) -> Self {
+        Self {
+            task_id: task_id.to_string(),
+            file_path: file_path.to_string(),
+            output_folder: output_folder.to_string(),
+            backend_url: backend_url.to_string(),
+            target_languages: target_languages.to_vec(),
+            options,
+            audio_track: AudioTrackSelection::default(),
+            subtitle_track: SubtitleTrackSelection::default(),
+            stage: JobStage::Queued,
+            audio_path: None,
+            original_srt_path: None,
+            detected_language: None,
+            translated_srt_paths: BTreeMap::new(),
+            translation_statuses: BTreeMap::new(),
+            job_id: None,
+            uploads: BTreeMap::new(),
+            error: None,
+            updated_at: chrono::Utc::now().to_rfc3339(),
+        }
+    }
+
+    /// The language muxed as the default track and used for burn-in
+    pub fn primary_language(&self) -> &str {
+        self.target_languages
+            .first()
+            .map(String::as_str)
+            .unwrap_or_default()
+    }
+}
+
+fn deserialize_target_languages<'de, D>(
+    deserializer: D,
+) -> std::result::Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(TargetLanguages::deserialize(deserializer)?.into_vec())
+}
+
+/// Get the jobs directory path (sibling of the logs directory)
+pub async fn get_jobs_dir(app_handle: &AppHandle) -> Result<PathBuf> {
+    let app_data_dir = app_handle
+        .path()
+        .app_data_dir()
+        .context("Failed to get app data directory")?;
+
+    let jobs_dir = app_data_dir.join("jobs");
+
+    // Create jobs directory if it doesn't exist
+    if !jobs_dir.exists() {
+        fs::create_dir_all(&jobs_dir)
+            .await
+            .context("Failed to create jobs directory")?;
+    }
+
+    Ok(jobs_dir)
+}
+
+/// Get the job file path for a specific task
+async fn get_job_path(app_handle: &AppHandle, task_id: &str) -> Result<PathBuf> {
+    let jobs_dir = get_jobs_dir(app_handle).await?;
+    Ok(jobs_dir.join(format!("{}.json", task_id)))
+}
+
+/// Write a job record, replacing any previous record for the same task
+pub async fn save_job(app_handle: &AppHandle, record: &JobRecord) -> Result<()> {
+    let job_path = get_job_path(app_handle, &record.task_id).await?;
+    let json = serde_json::to_string_pretty(record).context("Failed to serialize job record")?;
+
+    // Write to a temp file first so a crash mid-write never leaves a truncated record
+    let temp_path = job_path.with_extension("json.tmp");
+    fs::write(&temp_path, json)
+        .await
+        .context("Failed to write job record")?;
+    fs::rename(&temp_path, &job_path)
+        .await
+        .context("Failed to replace job record")?;
+
+    Ok(())
+}
+
+/// Apply a change to a task's job record and save it.
+/// Does nothing if the task has no record (e.g. it already finished).
+pub async fn update_job<F>(app_handle: &AppHandle, task_id: &str, change: F) -> Result<()>
+where
+    F: FnOnce(&mut JobRecord),
+{
+    let Some(mut record) = load_job(app_handle, task_id).await? else {
+        return Ok(());
+    };
+
+    change(&mut record);
+    record.updated_at = chrono::Utc::now().to_rfc3339();
+    save_job(app_handle, &record).await
+}
+
+/// Read a single job record
+pub async fn load_job(app_handle: &AppHandle, task_id: &str) -> Result<Option<JobRecord>> {
+    let job_path = get_job_path(app_handle, task_id).await?;
+
+    if !job_path.exists() {
+        return Ok(None);
+    }
+
+    let content = fs::read_to_string(&job_path)
+        .await
+        .context("Failed to read job record")?;
+    let record = serde_json::from_str(&content).context("Failed to parse job record")?;
+
+    Ok(Some(record))
+}
+
+/// Read all persisted job records, skipping files that cannot be parsed
+pub async fn load_all_jobs(app_handle: &AppHandle) -> Result<Vec<JobRecord>> {
+    let jobs_dir = get_jobs_dir(app_handle).await?;
+
+    let mut entries = fs::read_dir(&jobs_dir)
+        .await
+        .context("Failed to read jobs directory")?;
+
+    let mut records = Vec::new();
+    while let Some(entry) = entries
+        .next_entry()
+        .await
+        .context("Failed to read jobs directory entry")?
+    {
+        let path = entry.path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+            continue;
+        }
+
+        let content = match fs::read_to_string(&path).await {
+            Ok(content) => content,
+            Err(e) => {
+                eprintln!("Failed to read job file {}: {}", path.display(), e);
+                continue;
+            }
+        };
+
+        match serde_json::from_str::<JobRecord>(&content) {
+            Ok(record) => records.push(record),
+            Err(e) => {
+                eprintln!("Failed to parse job file {} - Error: {}", path.display(), e);
+            }
+        }
+    }
+
+    records.sort_by(|a, b| a.updated_at.cmp(&b.updated_at));
+
+    Ok(records)
+}
+
+/// Remove a task's job record once the task has finished or been cancelled, or is dismissed
+pub async fn remove_job(app_handle: &AppHandle, task_id: &str) -> Result<()> {
+    let job_path = get_job_path(app_handle, task_id).await?;
+
+    match fs::remove_file(&job_path).await {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(e).context("Failed to remove job record"),
+    }
+}
@@ -0,0 +1,67 @@
+// ISO 639-1 code, ISO 639-2 code(s) (bibliographic first) and English name of
+// common languages, since containers may tag streams with either code form and
+// the frontend passes target languages by name
+const LANGUAGE_ALIASES: &[&[&str]] = &[
+    &["en", "eng", "english"],
+    &["zh", "chi", "zho", "chinese"],
+    &["ja", "jpn", "japanese"],
+    &["ko", "kor", "korean"],
+    &["fr", "fre", "fra", "french"],
+    &["de", "ger", "deu", "german"],
+    &["es", "spa", "spanish"],
+    &["it", "ita", "italian"],
+    &["pt", "por", "portuguese"],
+    &["ru", "rus", "russian"],
+    &["ar", "ara", "arabic"],
+    &["hi", "hin", "hindi"],
+    &["th", "tha", "thai"],
+    &["vi", "vie", "vietnamese"],
+    &["nl", "dut", "nld", "dutch"],
+];
+
+/// Lowercase the primary part of a language tag or name,
+/// e.g. "en-US" -> "en", "Chinese Simplified" -> "chinese"
+fn normalize_language(value: &str) -> String {
+    value
+        .split(['-', '_', ' '])
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_lowercase()
+}
+
+/// Compare two language tags, treating ISO 639-1 and 639-2 codes and English
+/// names as equal and ignoring region suffixes (e.g. "en-US")
+pub fn language_matches(tag: &str, wanted: &str) -> bool {
+    let tag = normalize_language(tag);
+    let wanted = normalize_language(wanted);
+
+    if tag.is_empty() || wanted.is_empty() {
+        return false;
+    }
+    if tag == wanted {
+        return true;
+    }
+
+    LANGUAGE_ALIASES
+        .iter()
+        .any(|aliases| aliases.contains(&tag.as_str()) && aliases.contains(&wanted.as_str()))
+}
+
+/// ISO 639-2/B code for a language tag or name, as used in container metadata
+pub fn iso639_2_code(language: &str) -> Option<&'static str> {
+    let language = normalize_language(language);
+    LANGUAGE_ALIASES
+        .iter()
+        .find(|aliases| aliases.contains(&language.as_str()))
+        .map(|aliases| aliases[1])
+}
+
+/// ISO 639-1 code for a language tag or name, as expected by most translation APIs
+pub fn iso639_1_code(language: &str) -> Option<&'static str> {
+    let language = normalize_language(language);
+    LANGUAGE_ALIASES
+        .iter()
+        .find(|aliases| aliases.contains(&language.as_str()))
+        .map(|aliases| aliases[0])
+}
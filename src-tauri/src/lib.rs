@@ -1,184 +1,764 @@
+mod audio_split;
 mod backend_transcription;
 mod ffmpeg;
+mod glossary;
+mod job_store;
+mod language;
+mod libretranslate;
 mod logger;
+mod openai_translation;
+mod sse;
+mod subtitle;
+mod target_languages;
+mod task_registry;
+mod transcription;
 mod translation;
+mod translation_memory;
+mod translation_provider;
+mod translation_status;
+mod whisper_cpp;
 
-use ffmpeg::{extract_audio_to_wav, TaskErrorPayload, TaskInfo};
+use ffmpeg::{
+    extract_audio, AudioTrackSelection, MuxSubtitleTrack, SubtitleTrackSelection, TaskErrorPayload,
+    TaskInfo, TaskStartedPayload,
+};
+use job_store::{JobOptions, JobRecord, JobStage, SubtitleSource};
 use serde::Serialize;
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::Arc;
+use subtitle::SubtitleFormat;
+use target_languages::TargetLanguages;
+use task_registry::TaskCancelledPayload;
 use tauri::{Emitter, Window};
+use tokio::sync::Semaphore;
+use tokio_util::sync::CancellationToken;
+use transcription::TranscriptionProviderConfig;
+use translation::SrtBatchOptions;
+use translation_provider::TranslationProviderConfig;
+use translation_status::TranslationStatus;
 
 #[derive(Debug, Clone, Serialize)]
 struct BatchCompletePayload {}
 
+/// Fail a task whose id is already registered by a run still in progress
+fn emit_already_running(window: &Window, task_id: &str) {
+    let _ = window.emit(
+        "task:failed",
+        TaskErrorPayload {
+            task_id: task_id.to_string(),
+            error: "Task is already running".to_string(),
+        },
+    );
+}
+
+/// Remove a cancelled task's temp files and notify the frontend
+async fn finish_cancelled_task(
+    task_id: &str,
+    temp_paths: &[&str],
+    window: &Window,
+    app_handle: &tauri::AppHandle,
+) {
+    let cleanup_errors = remove_temp_files(temp_paths).await;
+
+    let message = if cleanup_errors.is_empty() {
+        "Task cancelled, temporary files cleaned up".to_string()
+    } else {
+        format!(
+            "Task cancelled. Warning: Cleanup errors: {}",
+            cleanup_errors.join(", ")
+        )
+    };
+    let _ = logger::append_log_entry(app_handle, window, task_id, "metadata", &message).await;
+
+    let _ = window.emit(
+        "task:cancelled",
+        TaskCancelledPayload {
+            task_id: task_id.to_string(),
+        },
+    );
+}
+
 // Learn more about Tauri commands at https://tauri.app/develop/calling-rust/
 #[tauri::command]
 fn greet(name: &str) -> String {
     format!("Hello, {}! You've been greeted from Rust!", name)
 }
 
-#[tauri::command]
-async fn extract_audio_batch(
-    tasks: Vec<TaskInfo>,
-    output_folder: String,
-    backend_url: String,
-    target_language: String,
+/// Remove temp files, ignoring ones that are already gone. Returns cleanup errors.
+async fn remove_temp_files(paths: &[&str]) -> Vec<String> {
+    let mut cleanup_errors = Vec::new();
+    for path in paths {
+        if let Err(e) = tokio::fs::remove_file(path).await {
+            if e.kind() != std::io::ErrorKind::NotFound {
+                cleanup_errors.push(format!("{}: {}", path, e));
+            }
+        }
+    }
+    cleanup_errors
+}
+
+/// How a video task's run ended
+enum TaskOutcome {
+    Completed,
+    /// The error to report with `task:failed`
+    Failed(String),
+    /// `task:cancelled` was already emitted
+    Cancelled,
+}
+
+/// Run the video pipeline (extract → transcribe → translate → export) for one task,
+/// starting from whatever stage its persisted job record has already reached
+async fn process_video_task(
+    record: JobRecord,
+    chunk_slots: &Arc<Semaphore>,
+    window: &Window,
+    app_handle: &tauri::AppHandle,
+    cancel_token: &CancellationToken,
+) -> TaskOutcome {
+    let task_id = record.task_id.as_str();
+    let file_exists =
+        |path: &Option<String>| path.as_deref().is_some_and(|p| Path::new(p).exists());
+
+    // Every target language already translated: only the export stage is left
+    let translated_ready = record.stage >= JobStage::Translating
+        && record
+            .target_languages
+            .iter()
+            .all(|language| file_exists(&record.translated_srt_paths.get(language).cloned()));
+    // Original SRT already downloaded: go straight to translation
+    let srt_ready = translated_ready
+        || (record.stage >= JobStage::Translating && file_exists(&record.original_srt_path));
+    // Backend job already created: re-attach to it instead of re-uploading
+    let job_ready = !srt_ready && record.stage >= JobStage::Transcribing && record.job_id.is_some();
+    // Audio already extracted: upload it without running ffmpeg again
+    let audio_ready = record.stage >= JobStage::Uploading && file_exists(&record.audio_path);
+
+    let resuming = srt_ready || job_ready || audio_ready;
+
+    if resuming {
+        let _ = logger::init_task_log(app_handle, task_id).await;
+        let _ = logger::append_log_entry(
+            app_handle,
+            window,
+            task_id,
+            "metadata",
+            &format!(
+                "Resuming task for {} from stage: {:?}",
+                record.file_path, record.stage
+            ),
+        )
+        .await;
+        let _ = window.emit(
+            "task:started",
+            TaskStartedPayload {
+                task_id: task_id.to_string(),
+            },
+        );
+    }
+
+    // A new task probes its input once for both extraction steps below
+    let media_info = if resuming {
+        None
+    } else {
+        match ffmpeg::start_media_task(task_id, &record.file_path, window, app_handle).await {
+            Ok(media_info) => Some(media_info),
+            Err(e) => return TaskOutcome::Failed(format!("Media probe failed: {}", e)),
+        }
+    };
+
+    // Step 0: Use an embedded text subtitle instead of a paid transcription, if requested
+    let embedded_srt_path = match &media_info {
+        Some(media_info) if record.options.subtitle_source != SubtitleSource::Transcribe => {
+            let _ = job_store::update_job(app_handle, task_id, |job| {
+                job.stage = JobStage::Extracting;
+            })
+            .await;
+
+            match ffmpeg::extract_subtitle_to_srt(
+                task_id,
+                &record.file_path,
+                &record.subtitle_track,
+                media_info,
+                window,
+                app_handle,
+                cancel_token,
+            )
+            .await
+            {
+                Ok(Some(path)) => {
+                    let _ = job_store::update_job(app_handle, task_id, |job| {
+                        job.stage = JobStage::Translating;
+                        job.original_srt_path = Some(path.clone());
+                    })
+                    .await;
+                    Some(path)
+                }
+                Ok(None) if record.options.subtitle_source == SubtitleSource::Auto => {
+                    let _ = logger::append_log_entry(
+                        app_handle,
+                        window,
+                        task_id,
+                        "metadata",
+                        "Falling back to audio transcription",
+                    )
+                    .await;
+                    None
+                }
+                Ok(None) => {
+                    return TaskOutcome::Failed(
+                        "Subtitle extraction failed: No embedded text subtitle stream found"
+                            .to_string(),
+                    )
+                }
+                Err(e) if task_registry::is_cancelled(&e) => {
+                    // ffmpeg already removed its partial output
+                    finish_cancelled_task(task_id, &[], window, app_handle).await;
+                    return TaskOutcome::Cancelled;
+                }
+                Err(e) => return TaskOutcome::Failed(format!("Subtitle extraction failed: {}", e)),
+            }
+        }
+        _ => None,
+    };
+    let srt_ready = srt_ready || embedded_srt_path.is_some();
+
+    // Step 1: Extract audio to temp directory
+    let audio_path = match &media_info {
+        Some(media_info) if !srt_ready => {
+            let _ = job_store::update_job(app_handle, task_id, |job| {
+                job.stage = JobStage::Extracting;
+            })
+            .await;
+
+            let audio_codec = record
+                .options
+                .transcription_provider
+                .audio_codec(record.options.audio_codec);
+            match extract_audio(
+                task_id,
+                &record.file_path,
+                &record.audio_track,
+                audio_codec,
+                media_info,
+                window,
+                app_handle,
+                cancel_token,
+            )
+            .await
+            {
+                Ok(path) => {
+                    let _ = job_store::update_job(app_handle, task_id, |job| {
+                        job.stage = JobStage::Uploading;
+                        job.audio_path = Some(path.clone());
+                    })
+                    .await;
+                    Some(path)
+                }
+                Err(e) if task_registry::is_cancelled(&e) => {
+                    // ffmpeg already removed its partial output
+                    finish_cancelled_task(task_id, &[], window, app_handle).await;
+                    return TaskOutcome::Cancelled;
+                }
+                Err(e) => return TaskOutcome::Failed(format!("Audio extraction failed: {}", e)),
+            }
+        }
+        _ => record.audio_path.clone(),
+    };
+    let audio_path_str = audio_path.as_deref().unwrap_or_default();
+
+    // Step 2: Transcribe audio (returns temp SRT path and the detected language)
+    let mut detected_language = record.detected_language.clone();
+    let original_srt_path = if srt_ready {
+        embedded_srt_path
+            .or_else(|| record.original_srt_path.clone())
+            .unwrap_or_default()
+    } else {
+        let transcription_result = match record.job_id.as_deref() {
+            Some(job_id) if job_ready => {
+                transcription::resume_transcription(
+                    &record.options.transcription_provider,
+                    &record.backend_url,
+                    task_id,
+                    job_id,
+                    audio_path_str,
+                    &record.file_path,
+                    window,
+                    app_handle,
+                    cancel_token,
+                )
+                .await
+            }
+            _ => {
+                transcription::transcribe_audio(
+                    &record.options.transcription_provider,
+                    &record.backend_url,
+                    task_id,
+                    audio_path_str,
+                    &record.file_path,
+                    window,
+                    app_handle,
+                    cancel_token,
+                )
+                .await
+            }
+        };
+
+        match transcription_result {
+            Ok(transcript) => {
+                let _ = job_store::update_job(app_handle, task_id, |job| {
+                    job.stage = JobStage::Translating;
+                    job.original_srt_path = Some(transcript.srt_path.clone());
+                    job.detected_language = transcript.language_code.clone();
+                })
+                .await;
+                detected_language = transcript.language_code;
+                transcript.srt_path
+            }
+            Err(e) if task_registry::is_cancelled(&e) => {
+                finish_cancelled_task(task_id, &[audio_path_str], window, app_handle).await;
+                return TaskOutcome::Cancelled;
+            }
+            Err(e) => {
+                // Transcription failed: Keep temp audio file for debugging
+                let _ = logger::append_log_entry(
+                    app_handle,
+                    window,
+                    task_id,
+                    "metadata",
+                    &format!("Keeping temp audio file for debugging: {}", audio_path_str),
+                )
+                .await;
+
+                return TaskOutcome::Failed(format!("Transcription failed: {}", e));
+            }
+        }
+    };
+
+    // Step 3: Translate SRT into every target language not done yet (with fallback to original on failure)
+    // Languages finished before a restart are kept; their recorded SRT and status is all we know about
+    let mut language_outputs: BTreeMap<String, Vec<String>> = record
+        .translated_srt_paths
+        .iter()
+        .filter(|(_, path)| Path::new(path).exists())
+        .map(|(language, path)| (language.clone(), vec![path.clone()]))
+        .collect();
+    let mut language_statuses: BTreeMap<String, TranslationStatus> = language_outputs
+        .keys()
+        .map(|language| {
+            let status = record
+                .translation_statuses
+                .get(language)
+                .copied()
+                .unwrap_or(TranslationStatus::Translated);
+            (language.clone(), status)
+        })
+        .collect();
+    let mut export_srt_paths: BTreeMap<String, String> = language_outputs
+        .iter()
+        .filter_map(|(language, paths)| Some((language.clone(), paths.first()?.clone())))
+        .collect();
+    let pending_languages: Vec<String> = record
+        .target_languages
+        .iter()
+        .filter(|language| !language_outputs.contains_key(*language))
+        .cloned()
+        .collect();
+
+    // An explicit source language wins over the one detected by the transcription backend
+    let mut translation_options = record.options.translation.clone();
+    if translation_options.source_language.is_none() {
+        translation_options.source_language = detected_language;
+    }
+
+    // Muxing and burn-in take SRT, so it is written along with any other requested format
+    let mut output_options = record.options.output.clone();
+    if (record.options.mux_subtitles || record.options.hardsub.is_some())
+        && !output_options.formats.contains(&SubtitleFormat::Srt)
+    {
+        output_options.formats.push(SubtitleFormat::Srt);
+    }
+
+    // Languages whose translation and fallback both failed; the others are still exported
+    let mut translation_failure = None;
+    if !pending_languages.is_empty() {
+        let translation_result = translation::translate_srt_languages(
+            &record.backend_url,
+            task_id,
+            &original_srt_path,
+            &pending_languages,
+            &record.output_folder,
+            &record.file_path,
+            &output_options,
+            &translation_options,
+            chunk_slots,
+            window,
+            app_handle,
+            cancel_token,
+        )
+        .await;
+
+        match translation_result {
+            Ok(results) => {
+                if let Some(summary) = results.failure_summary() {
+                    let _ = logger::append_log_entry(
+                        app_handle,
+                        window,
+                        task_id,
+                        "error",
+                        &format!("Translation and fallback both failed: {}", summary),
+                    )
+                    .await;
+                    translation_failure = Some(summary);
+                }
+                for result in results.outputs {
+                    language_statuses.insert(result.language.clone(), result.status);
+                    if let Some(srt_path) = result.path_for(SubtitleFormat::Srt) {
+                        export_srt_paths.insert(result.language.clone(), srt_path.to_string());
+                    }
+                    language_outputs.insert(result.language.clone(), result.paths());
+                }
+            }
+            Err(e) if task_registry::is_cancelled(&e) => {
+                finish_cancelled_task(
+                    task_id,
+                    &[audio_path_str, &original_srt_path],
+                    window,
+                    app_handle,
+                )
+                .await;
+                return TaskOutcome::Cancelled;
+            }
+            Err(e) => {
+                // Translation failed catastrophically (even fallback failed)
+                let _ = logger::append_log_entry(
+                    app_handle,
+                    window,
+                    task_id,
+                    "error",
+                    &format!("Translation and fallback both failed: {}", e),
+                )
+                .await;
+
+                // Keep temp files for debugging
+                let _ = logger::append_log_entry(
+                    app_handle,
+                    window,
+                    task_id,
+                    "metadata",
+                    &format!(
+                        "Keeping temp files for debugging: audio={}, srt={}",
+                        audio_path_str, original_srt_path
+                    ),
+                )
+                .await;
+
+                return TaskOutcome::Failed(format!("Translation failed: {}", e));
+            }
+        }
+    }
+
+    let output_paths: Vec<String> = record
+        .target_languages
+        .iter()
+        .filter_map(|language| language_outputs.get(language))
+        .flatten()
+        .cloned()
+        .collect();
+    let translation_status = TranslationStatus::combine(
+        record
+            .target_languages
+            .iter()
+            .filter_map(|language| language_statuses.get(language).copied()),
+    );
+    let _ =
+        translation::emit_translation_complete(window, task_id, output_paths, translation_status);
+
+    // Partial and untranslated output is not muxed or burned in, where it would pass for a translation
+    let exporting = record.options.mux_subtitles || record.options.hardsub.is_some();
+    let not_exported: Vec<&str> = record
+        .target_languages
+        .iter()
+        .filter(|language| {
+            language_statuses
+                .get(*language)
+                .is_some_and(|status| *status != TranslationStatus::Translated)
+        })
+        .map(String::as_str)
+        .collect();
+    if exporting && !not_exported.is_empty() {
+        let _ = logger::append_log_entry(
+            app_handle,
+            window,
+            task_id,
+            "metadata",
+            &format!(
+                "Not muxing or burning in subtitles that are not fully translated: {}",
+                not_exported.join(", ")
+            ),
+        )
+        .await;
+    }
+    export_srt_paths.retain(|language, _| {
+        language_statuses.get(language) == Some(&TranslationStatus::Translated)
+    });
+
+    // Step 4: Optionally mux and/or burn the subtitles into copies of the video
+    if exporting {
+        let _ = job_store::update_job(app_handle, task_id, |job| {
+            job.stage = JobStage::Exporting;
+        })
+        .await;
+    }
+
+    if record.options.mux_subtitles && !export_srt_paths.is_empty() {
+        // One track per translated target language, the first one as default
+        let mut tracks: Vec<MuxSubtitleTrack> = record
+            .target_languages
+            .iter()
+            .filter_map(|language| {
+                let path = export_srt_paths.get(language)?;
+                Some(MuxSubtitleTrack {
+                    srt_path: path.to_string(),
+                    language: Some(language.clone()),
+                    title: language.clone(),
+                    is_default: language == record.primary_language(),
+                })
+            })
+            .collect();
+        if record.options.mux_include_original && Path::new(&original_srt_path).exists() {
+            tracks.push(MuxSubtitleTrack {
+                srt_path: original_srt_path.clone(),
+                language: record
+                    .audio_track
+                    .language
+                    .clone()
+                    .or_else(|| record.subtitle_track.language.clone()),
+                title: "Original".to_string(),
+                is_default: false,
+            });
+        }
+
+        let mux_result = ffmpeg::mux_subtitles(
+            task_id,
+            &record.file_path,
+            &tracks,
+            &record.output_folder,
+            &record.target_languages.join("-"),
+            window,
+            app_handle,
+            cancel_token,
+        )
+        .await;
+
+        match mux_result {
+            Ok(_muxed_path) => {}
+            Err(e) if task_registry::is_cancelled(&e) => {
+                finish_cancelled_task(
+                    task_id,
+                    &[audio_path_str, &original_srt_path],
+                    window,
+                    app_handle,
+                )
+                .await;
+                return TaskOutcome::Cancelled;
+            }
+            Err(e) => {
+                // The translated SRT is already in the output folder; keep temp files for debugging
+                let _ = logger::append_log_entry(
+                    app_handle,
+                    window,
+                    task_id,
+                    "error",
+                    &format!("Subtitle muxing failed: {}", e),
+                )
+                .await;
+
+                return TaskOutcome::Failed(format!("Subtitle muxing failed: {}", e));
+            }
+        }
+    }
+
+    // An ASS rendition is burned in with its own styles; it sits next to the SRT under the same stem
+    let hardsub_path = export_srt_paths
+        .get(record.primary_language())
+        .map(|srt_path| {
+            let ass_path = Path::new(srt_path).with_extension(SubtitleFormat::Ass.extension());
+            if output_options.formats.contains(&SubtitleFormat::Ass) && ass_path.exists() {
+                ass_path.to_string_lossy().into_owned()
+            } else {
+                srt_path.clone()
+            }
+        });
+    if let (Some(style), Some(hardsub_path)) = (&record.options.hardsub, &hardsub_path) {
+        let burn_result = ffmpeg::burn_subtitles(
+            task_id,
+            &record.file_path,
+            hardsub_path,
+            style,
+            &record.audio_track,
+            &record.output_folder,
+            record.primary_language(),
+            window,
+            app_handle,
+            cancel_token,
+        )
+        .await;
+
+        match burn_result {
+            Ok(_hardsub_path) => {}
+            Err(e) if task_registry::is_cancelled(&e) => {
+                finish_cancelled_task(
+                    task_id,
+                    &[audio_path_str, &original_srt_path],
+                    window,
+                    app_handle,
+                )
+                .await;
+                return TaskOutcome::Cancelled;
+            }
+            Err(e) => {
+                let _ = logger::append_log_entry(
+                    app_handle,
+                    window,
+                    task_id,
+                    "error",
+                    &format!("Subtitle burn-in failed: {}", e),
+                )
+                .await;
+
+                return TaskOutcome::Failed(format!("Subtitle burn-in failed: {}", e));
+            }
+        }
+    }
+
+    // A resumed job retries the failed languages from the kept temp files
+    if let Some(summary) = translation_failure {
+        let _ = logger::append_log_entry(
+            app_handle,
+            window,
+            task_id,
+            "metadata",
+            &format!(
+                "Keeping temp files for debugging: audio={}, srt={}",
+                audio_path_str, original_srt_path
+            ),
+        )
+        .await;
+        return TaskOutcome::Failed(format!("Translation failed: {}", summary));
+    }
+
+    // Success: Clean up temp audio and temp original SRT
+    let cleanup_errors = remove_temp_files(&[audio_path_str, &original_srt_path]).await;
+
+    if !cleanup_errors.is_empty() {
+        let _ = logger::append_log_entry(
+            app_handle,
+            window,
+            task_id,
+            "metadata",
+            &format!("Warning: Cleanup errors: {}", cleanup_errors.join(", ")),
+        )
+        .await;
+    } else {
+        let _ = logger::append_log_entry(
+            app_handle,
+            window,
+            task_id,
+            "metadata",
+            "All temporary files cleaned up successfully",
+        )
+        .await;
+    }
+
+    TaskOutcome::Completed
+}
+
+/// Register every job's task up front so queued tasks can be cancelled too.
+/// Jobs whose task is already running fail with `task:failed` and are left out.
+fn register_video_jobs(
+    records: Vec<JobRecord>,
+    window: &Window,
+) -> Vec<(JobRecord, CancellationToken)> {
+    records
+        .into_iter()
+        .filter_map(|record| match task_registry::register(&record.task_id) {
+            Some(cancel_token) => Some((record, cancel_token)),
+            None => {
+                emit_already_running(window, &record.task_id);
+                None
+            }
+        })
+        .collect()
+}
+
+/// Process registered video jobs, up to 4 in parallel, then emit `batch:complete`
+async fn run_video_jobs(
+    jobs: Vec<(JobRecord, CancellationToken)>,
     window: Window,
     app_handle: tauri::AppHandle,
-) -> Result<(), String> {
-    // Validate backend is accessible before processing
-    backend_transcription::validate_backend(&backend_url)
-        .await
-        .map_err(|e| format!("Backend validation failed: {}", e))?;
-
-    // Process up to 4 tasks in parallel
+) {
     let mut handles = Vec::new();
     let semaphore = std::sync::Arc::new(tokio::sync::Semaphore::new(4));
+    let chunk_slots = translation::chunk_slots();
 
-    for task in tasks {
-        let permit = semaphore.clone().acquire_owned().await.unwrap();
+    for (record, cancel_token) in jobs {
+        let semaphore = semaphore.clone();
+        let chunk_slots = chunk_slots.clone();
         let window_clone = window.clone();
-        let output_folder_clone = output_folder.clone();
         let app_handle_clone = app_handle.clone();
-        let backend_url_clone = backend_url.clone();
-        let target_language_clone = target_language.clone();
 
         let handle = tokio::spawn(async move {
-            // Step 1: Extract audio to temp directory
-            let extraction_result =
-                extract_audio_to_wav(&task.id, &task.file_path, &window_clone, &app_handle_clone)
-                    .await;
+            let task_id = record.task_id.clone();
 
-            match extraction_result {
-                Ok(audio_path) => {
-                    // Step 2: Transcribe audio (returns temp SRT path)
-                    let transcription_result = backend_transcription::transcribe_audio(
-                        &backend_url_clone,
-                        &task.id,
-                        &audio_path,
-                        &task.file_path,
-                        &window_clone,
-                        &app_handle_clone,
-                    )
-                    .await;
-
-                    match transcription_result {
-                        Ok(original_srt_path) => {
-                            // Step 3: Translate SRT (with fallback to original on failure)
-                            let translation_result = translation::translate_srt(
-                                &backend_url_clone,
-                                &task.id,
-                                &original_srt_path,
-                                &target_language_clone,
-                                &output_folder_clone,
-                                &task.file_path,
-                                &window_clone,
-                                &app_handle_clone,
-                            )
-                            .await;
-
-                            match translation_result {
-                                Ok(_final_srt_path) => {
-                                    // Success: Clean up temp audio and temp original SRT
-                                    let mut cleanup_errors = Vec::new();
-
-                                    if let Err(e) = tokio::fs::remove_file(&audio_path).await {
-                                        cleanup_errors.push(format!("temp audio: {}", e));
-                                    }
-
-                                    if let Err(e) = tokio::fs::remove_file(&original_srt_path).await
-                                    {
-                                        cleanup_errors.push(format!("temp SRT: {}", e));
-                                    }
-
-                                    if !cleanup_errors.is_empty() {
-                                        let _ = logger::append_log_entry(
-                                            &app_handle_clone,
-                                            &window_clone,
-                                            &task.id,
-                                            "metadata",
-                                            &format!(
-                                                "Warning: Cleanup errors: {}",
-                                                cleanup_errors.join(", ")
-                                            ),
-                                        )
-                                        .await;
-                                    } else {
-                                        let _ = logger::append_log_entry(
-                                            &app_handle_clone,
-                                            &window_clone,
-                                            &task.id,
-                                            "metadata",
-                                            "All temporary files cleaned up successfully",
-                                        )
-                                        .await;
-                                    }
-                                }
-                                Err(e) => {
-                                    // Translation failed catastrophically (even fallback failed)
-                                    let _ = logger::append_log_entry(
-                                        &app_handle_clone,
-                                        &window_clone,
-                                        &task.id,
-                                        "error",
-                                        &format!("Translation and fallback both failed: {}", e),
-                                    )
-                                    .await;
-
-                                    // Keep temp files for debugging
-                                    let _ = logger::append_log_entry(
-                                        &app_handle_clone,
-                                        &window_clone,
-                                        &task.id,
-                                        "metadata",
-                                        &format!(
-                                            "Keeping temp files for debugging: audio={}, srt={}",
-                                            audio_path, original_srt_path
-                                        ),
-                                    )
-                                    .await;
-
-                                    let _ = window_clone.emit(
-                                        "task:failed",
-                                        TaskErrorPayload {
-                                            task_id: task.id.clone(),
-                                            error: format!("Translation failed: {}", e),
-                                        },
-                                    );
-                                }
-                            }
-                        }
-                        Err(e) => {
-                            // Transcription failed: Keep temp audio file for debugging
-                            let _ = logger::append_log_entry(
-                                &app_handle_clone,
-                                &window_clone,
-                                &task.id,
-                                "metadata",
-                                &format!("Keeping temp audio file for debugging: {}", audio_path),
-                            )
-                            .await;
-
-                            let _ = window_clone.emit(
-                                "task:failed",
-                                TaskErrorPayload {
-                                    task_id: task.id.clone(),
-                                    error: format!("Transcription failed: {}", e),
-                                },
-                            );
-                        }
-                    }
+            // Wait for a free slot, unless the task is cancelled while queued
+            let permit = tokio::select! {
+                permit = semaphore.acquire_owned() => permit.unwrap(),
+                _ = cancel_token.cancelled() => {
+                    finish_cancelled_task(&task_id, &[], &window_clone, &app_handle_clone).await;
+                    let _ = job_store::remove_job(&app_handle_clone, &task_id).await;
+                    task_registry::unregister(&task_id);
+                    return;
                 }
-                Err(e) => {
-                    // Audio extraction failed
+            };
+
+            // A failed job that is resumed starts over without its old error
+            if record.error.is_some() {
+                let _ = job_store::update_job(&app_handle_clone, &task_id, |job| {
+                    job.error = None;
+                })
+                .await;
+            }
+
+            let outcome = process_video_task(
+                record,
+                &chunk_slots,
+                &window_clone,
+                &app_handle_clone,
+                &cancel_token,
+            )
+            .await;
+
+            match outcome {
+                TaskOutcome::Failed(error) => {
+                    // Keep the record so the user can resume or dismiss the failed job
+                    let _ = job_store::update_job(&app_handle_clone, &task_id, |job| {
+                        job.error = Some(error.clone());
+                    })
+                    .await;
                     let _ = window_clone.emit(
                         "task:failed",
                         TaskErrorPayload {
-                            task_id: task.id.clone(),
-                            error: format!("Audio extraction failed: {}", e),
+                            task_id: task_id.clone(),
+                            error,
                         },
                     );
                 }
+                TaskOutcome::Completed | TaskOutcome::Cancelled => {
+                    let _ = job_store::remove_job(&app_handle_clone, &task_id).await;
+                }
             }
+            task_registry::unregister(&task_id);
 
             // Release the permit
             drop(permit);
@@ -194,48 +774,237 @@ async fn extract_audio_batch(
 
     // Emit batch complete event
     let _ = window.emit("batch:complete", BatchCompletePayload {});
+}
+
+#[tauri::command]
+async fn extract_audio_batch(
+    tasks: Vec<TaskInfo>,
+    output_folder: String,
+    backend_url: String,
+    target_language: TargetLanguages,
+    options: Option<JobOptions>,
+    window: Window,
+    app_handle: tauri::AppHandle,
+) -> Result<(), String> {
+    let target_languages = target_language.into_vec();
+    if target_languages.is_empty() {
+        return Err("No target language specified".to_string());
+    }
+
+    // The backend is only needed to transcribe with it or to translate with the translation server
+    let options = options.unwrap_or_default();
+    let uses_backend = matches!(
+        options.transcription_provider,
+        TranscriptionProviderConfig::Backend
+    ) || matches!(
+        options.translation.provider,
+        TranslationProviderConfig::Server
+    );
+    if uses_backend {
+        backend_transcription::validate_backend(&backend_url)
+            .await
+            .map_err(|e| format!("Backend validation failed: {}", e))?;
+    }
+
+    transcription::validate_provider(&options.transcription_provider)
+        .await
+        .map_err(|e| format!("Transcription provider validation failed: {}", e))?;
+
+    // Persist every task before starting so the batch survives an app restart
+    let mut records = Vec::new();
+    for task in tasks {
+        // Per-task track choices override the batch-wide language preferences
+        let audio_track = AudioTrackSelection {
+            stream_index: task.audio_stream_index,
+            language: task
+                .audio_language
+                .or_else(|| options.preferred_audio_language.clone()),
+        };
+        let subtitle_track = SubtitleTrackSelection {
+            stream_index: task.subtitle_stream_index,
+            language: options.preferred_subtitle_language.clone(),
+        };
+        let mut record = JobRecord::new(
+            &task.id,
+            &task.file_path,
+            &output_folder,
+            &backend_url,
+            &target_languages,
+            options.clone(),
+        );
+        record.audio_track = audio_track;
+        record.subtitle_track = subtitle_track;
+        records.push(record);
+    }
+
+    // Only registered jobs are saved, so a run still in progress keeps its record
+    let jobs = register_video_jobs(records, &window);
+    for (record, _) in &jobs {
+        if let Err(e) = job_store::save_job(&app_handle, record).await {
+            for (record, _) in &jobs {
+                task_registry::unregister(&record.task_id);
+            }
+            return Err(format!("Failed to save job: {}", e));
+        }
+    }
+
+    run_video_jobs(jobs, window, app_handle).await;
 
     Ok(())
 }
 
+/// List video jobs left unfinished by a previous run of the app, or that failed
+#[tauri::command]
+async fn get_pending_jobs(app_handle: tauri::AppHandle) -> Result<Vec<JobRecord>, String> {
+    let records = job_store::load_all_jobs(&app_handle)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(records
+        .into_iter()
+        .filter(|record| !task_registry::is_registered(&record.task_id))
+        .collect())
+}
+
+/// Resume unfinished and failed video jobs, re-attaching to backend transcription
+/// jobs that were already created instead of uploading the audio again
+#[tauri::command]
+async fn resume_pending_jobs(window: Window, app_handle: tauri::AppHandle) -> Result<(), String> {
+    let records = get_pending_jobs(app_handle.clone()).await?;
+    let jobs = register_video_jobs(records, &window);
+
+    run_video_jobs(jobs, window, app_handle).await;
+
+    Ok(())
+}
+
+/// Discard a pending or failed video job along with the temp files it kept
+#[tauri::command]
+async fn dismiss_pending_job(task_id: String, app_handle: tauri::AppHandle) -> Result<(), String> {
+    if task_registry::is_registered(&task_id) {
+        return Err(format!("Task is still running: {}", task_id));
+    }
+
+    let record = job_store::load_job(&app_handle, &task_id)
+        .await
+        .map_err(|e| e.to_string())?;
+    if let Some(record) = record {
+        let temp_paths: Vec<&str> = [&record.audio_path, &record.original_srt_path]
+            .into_iter()
+            .filter_map(|path| path.as_deref())
+            .collect();
+        let cleanup_errors = remove_temp_files(&temp_paths).await;
+        if !cleanup_errors.is_empty() {
+            eprintln!("Cleanup errors: {}", cleanup_errors.join(", "));
+        }
+    }
+
+    job_store::remove_job(&app_handle, &task_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 async fn translate_srt_batch(
     tasks: Vec<TaskInfo>,
     output_folder: String,
-    target_language: String,
+    target_language: TargetLanguages,
     backend_url: String,
+    options: Option<SrtBatchOptions>,
     window: Window,
     app_handle: tauri::AppHandle,
 ) -> Result<(), String> {
+    let target_languages = target_language.into_vec();
+    if target_languages.is_empty() {
+        return Err("No target language specified".to_string());
+    }
+    let options = options.unwrap_or_default();
+
     // Process up to 4 tasks in parallel
     let mut handles = Vec::new();
     let semaphore = std::sync::Arc::new(tokio::sync::Semaphore::new(4));
+    let chunk_slots = translation::chunk_slots();
 
-    for task in tasks {
-        let permit = semaphore.clone().acquire_owned().await.unwrap();
+    // Register every task up front so queued tasks can be cancelled too
+    let cancel_tokens: Vec<_> = tasks
+        .iter()
+        .map(|task| task_registry::register(&task.id))
+        .collect();
+
+    for (task, cancel_token) in tasks.into_iter().zip(cancel_tokens) {
+        let Some(cancel_token) = cancel_token else {
+            emit_already_running(&window, &task.id);
+            continue;
+        };
+        let semaphore = semaphore.clone();
         let window_clone = window.clone();
         let output_folder_clone = output_folder.clone();
         let app_handle_clone = app_handle.clone();
-        let target_language_clone = target_language.clone();
+        let target_languages_clone = target_languages.clone();
         let backend_url_clone = backend_url.clone();
+        let options_clone = options.clone();
+        let chunk_slots = chunk_slots.clone();
 
         let handle = tokio::spawn(async move {
+            // Wait for a free slot, unless the task is cancelled while queued
+            let permit = tokio::select! {
+                permit = semaphore.acquire_owned() => permit.unwrap(),
+                _ = cancel_token.cancelled() => {
+                    finish_cancelled_task(&task.id, &[], &window_clone, &app_handle_clone).await;
+                    task_registry::unregister(&task.id);
+                    return;
+                }
+            };
+
             // Directly translate SRT (no audio extraction, no transcription)
-            let translation_result = translation::translate_srt(
+            let translation_result = translation::translate_srt_languages(
                 &backend_url_clone,
                 &task.id,
                 &task.file_path, // SRT file path (not video)
-                &target_language_clone,
+                &target_languages_clone,
                 &output_folder_clone,
                 &task.file_path, // Use same path for filename extraction
+                &options_clone.output,
+                &options_clone.translation,
+                &chunk_slots,
                 &window_clone,
                 &app_handle_clone,
+                &cancel_token,
             )
             .await;
 
             match translation_result {
-                Ok(_final_srt_path) => {
-                    // Success - translation complete event already emitted by translate_srt
+                Ok(results) => {
+                    if !results.outputs.is_empty() {
+                        let status = TranslationStatus::combine(
+                            results.outputs.iter().map(|result| result.status),
+                        );
+                        let output_paths = results
+                            .outputs
+                            .iter()
+                            .flat_map(|result| result.paths())
+                            .collect();
+                        let _ = translation::emit_translation_complete(
+                            &window_clone,
+                            &task.id,
+                            output_paths,
+                            status,
+                        );
+                    }
+                    // The languages that were written stay in the output folder
+                    if let Some(summary) = results.failure_summary() {
+                        let _ = window_clone.emit(
+                            "task:failed",
+                            TaskErrorPayload {
+                                task_id: task.id.clone(),
+                                error: format!("Translation failed: {}", summary),
+                            },
+                        );
+                    }
+                }
+                Err(e) if task_registry::is_cancelled(&e) => {
+                    // The source SRT belongs to the user, so there is nothing to clean up
+                    finish_cancelled_task(&task.id, &[], &window_clone, &app_handle_clone).await;
                 }
                 Err(e) => {
                     let _ = window_clone.emit(
@@ -248,6 +1017,8 @@ async fn translate_srt_batch(
                 }
             }
 
+            task_registry::unregister(&task.id);
+
             // Release the permit
             drop(permit);
         });
@@ -267,26 +1038,26 @@ async fn translate_srt_batch(
 }
 
 #[tauri::command]
-async fn cancel_extraction(task_id: String, window: Window) -> Result<(), String> {
-    // Note: Full cancellation implementation requires architectural changes:
-    // - Global state to track running FFmpeg processes and AssemblyAI operations
-    // - CancellationToken propagation through async functions
-    // - Process termination for FFmpeg
-    // - Cleanup of temporary files
-    //
-    // For now, this logs the cancellation request. Tasks will complete normally.
-
-    let _ = window.emit(
-        "task:failed",
-        TaskErrorPayload {
-            task_id: task_id.clone(),
-            error: "Task cancellation requested (Note: Cancellation not fully implemented - task may complete)".to_string(),
-        },
-    );
+async fn cancel_extraction(task_id: String) -> Result<(), String> {
+    // The running task kills its ffmpeg process / aborts its requests, cleans up
+    // its temp files and emits `task:cancelled` itself
+    if !task_registry::cancel(&task_id) {
+        return Err(format!("No running task found with ID: {}", task_id));
+    }
 
     Ok(())
 }
 
+#[tauri::command]
+async fn probe_media(
+    file_path: String,
+    app_handle: tauri::AppHandle,
+) -> Result<ffmpeg::MediaInfo, String> {
+    ffmpeg::probe_media(&app_handle, &file_path)
+        .await
+        .map_err(|e| format!("Failed to probe media: {}", e))
+}
+
 #[tauri::command]
 async fn get_task_logs(
     task_id: String,
@@ -309,6 +1080,54 @@ async fn get_log_folder(app_handle: tauri::AppHandle) -> Result<String, String>
         .map(|s| s.to_string())
 }
 
+/// Import a CSV/TSV glossary for a project, replacing its previous entries
+#[tauri::command]
+async fn import_glossary(
+    project: String,
+    file_path: String,
+    app_handle: tauri::AppHandle,
+) -> Result<glossary::Glossary, String> {
+    glossary::import_glossary(&app_handle, &project, &file_path)
+        .await
+        .map_err(|e| format!("{:#}", e))
+}
+
+#[tauri::command]
+async fn list_glossaries(app_handle: tauri::AppHandle) -> Result<Vec<glossary::Glossary>, String> {
+    glossary::load_all_glossaries(&app_handle)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+async fn delete_glossary(project: String, app_handle: tauri::AppHandle) -> Result<(), String> {
+    glossary::delete_glossary(&app_handle, &project)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Export the translation memory as TMX; returns the number of translation units
+#[tauri::command]
+async fn export_translation_memory(
+    file_path: String,
+    app_handle: tauri::AppHandle,
+) -> Result<usize, String> {
+    translation_memory::export_tmx(&app_handle, &file_path)
+        .await
+        .map_err(|e| format!("{:#}", e))
+}
+
+/// Merge a TMX file into the translation memory; returns the number of translations imported
+#[tauri::command]
+async fn import_translation_memory(
+    file_path: String,
+    app_handle: tauri::AppHandle,
+) -> Result<usize, String> {
+    translation_memory::import_tmx(&app_handle, &file_path)
+        .await
+        .map_err(|e| format!("{:#}", e))
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
@@ -321,8 +1140,17 @@ pub fn run() {
             extract_audio_batch,
             translate_srt_batch,
             cancel_extraction,
+            get_pending_jobs,
+            resume_pending_jobs,
+            dismiss_pending_job,
+            probe_media,
             get_task_logs,
-            get_log_folder
+            get_log_folder,
+            import_glossary,
+            list_glossaries,
+            delete_glossary,
+            export_translation_memory,
+            import_translation_memory
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -0,0 +1,120 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::glossary::GlossaryEntry;
+use crate::language;
+use crate::subtitle::Cue;
+use crate::translation_provider::{self, TranslationOptions, TranslationProvider};
+
+/// A LibreTranslate instance
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LibreTranslateSettings {
+    /// Instance URL, e.g. `http://localhost:5000`
+    pub url: String,
+    /// Required by instances that limit anonymous use
+    #[serde(rename = "apiKey", default, skip_serializing_if = "Option::is_none")]
+    pub api_key: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct LibreTranslateRequest<'a> {
+    q: Vec<&'a str>,
+    source: &'a str,
+    target: &'a str,
+    format: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    api_key: Option<&'a str>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LibreTranslateResponse {
+    #[serde(rename = "translatedText")]
+    translated_text: Vec<String>,
+}
+
+/// Translates cue texts in one batched `/translate` request.
+/// LibreTranslate has no glossary support; terms are only checked afterwards.
+pub struct LibreTranslateProvider {
+    settings: LibreTranslateSettings,
+    source: String,
+    target: String,
+}
+
+impl LibreTranslateProvider {
+    /// Fails if either language has no LibreTranslate code
+    pub fn new(
+        settings: LibreTranslateSettings,
+        source_language: Option<&str>,
+        target_language: &str,
+    ) -> Result<Self> {
+        let source = match source_language {
+            Some(language) => language_code(language)?,
+            None => "auto".to_string(),
+        };
+        Ok(Self {
+            settings,
+            source,
+            target: language_code(target_language)?,
+        })
+    }
+}
+
+/// LibreTranslate language code for a language name or tag,
+/// e.g. "Japanese" -> "ja", "Chinese Traditional" -> "zt"
+fn language_code(language: &str) -> Result<String> {
+    let lowercase = language.trim().to_lowercase();
+    if lowercase.starts_with("chinese") && lowercase.contains("traditional") {
+        return Ok("zt".to_string());
+    }
+    if let Some(code) = language::iso639_1_code(&lowercase) {
+        return Ok(code.to_string());
+    }
+    // Anything that already looks like a code is passed through, e.g. "uk"
+    if (2..=3).contains(&lowercase.len()) && lowercase.chars().all(|c| c.is_ascii_alphabetic()) {
+        return Ok(lowercase);
+    }
+    anyhow::bail!("No LibreTranslate language code for \"{}\"", language)
+}
+
+impl TranslationProvider for LibreTranslateProvider {
+    async fn translate_cues(
+        &self,
+        cues: &[Cue],
+        _target_language: &str,
+        _options: &TranslationOptions,
+        _glossary: &[GlossaryEntry],
+    ) -> Result<Vec<Cue>> {
+        let request_body = LibreTranslateRequest {
+            q: cues.iter().map(|cue| cue.text.as_str()).collect(),
+            source: &self.source,
+            target: &self.target,
+            format: "text",
+            api_key: self.settings.api_key.as_deref(),
+        };
+
+        let client = reqwest::Client::new();
+        let response = client
+            .post(format!(
+                "{}/translate",
+                self.settings.url.trim_end_matches('/')
+            ))
+            .timeout(translation_provider::REQUEST_TIMEOUT)
+            .json(&request_body)
+            .send()
+            .await
+            .context("Network error during LibreTranslate request")?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_default();
+            anyhow::bail!("[HTTP {}] LibreTranslate failed: {}", status, error_text);
+        }
+
+        let translation: LibreTranslateResponse = response
+            .json()
+            .await
+            .context("Failed to parse LibreTranslate response")?;
+
+        translation_provider::with_texts(cues, translation.translated_text)
+    }
+}
@@ -0,0 +1,186 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::glossary::GlossaryEntry;
+use crate::subtitle::Cue;
+use crate::translation_provider::{self, TranslationOptions, TranslationProvider};
+
+/// An OpenAI-compatible chat completions endpoint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpenAiSettings {
+    /// API base URL including the version, e.g. `http://localhost:11434/v1`
+    #[serde(rename = "baseUrl")]
+    pub base_url: String,
+    /// Sent as a bearer token; local servers usually need none
+    #[serde(rename = "apiKey", default, skip_serializing_if = "Option::is_none")]
+    pub api_key: Option<String>,
+    /// Model name as known to the endpoint, e.g. "qwen2.5:14b"
+    pub model: String,
+}
+
+#[derive(Debug, Serialize)]
+struct ChatMessage {
+    role: &'static str,
+    content: String,
+}
+
+#[derive(Debug, Serialize)]
+struct ChatCompletionRequest<'a> {
+    model: &'a str,
+    messages: Vec<ChatMessage>,
+    temperature: f32,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatCompletionResponse {
+    choices: Vec<ChatChoice>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatChoice {
+    message: ChatResponseMessage,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatResponseMessage {
+    content: String,
+}
+
+/// Translates cue texts with a chat model, sending them as a JSON array
+pub struct OpenAiProvider {
+    settings: OpenAiSettings,
+}
+
+impl OpenAiProvider {
+    pub fn new(settings: OpenAiSettings) -> Self {
+        Self { settings }
+    }
+}
+
+/// Instructions for the model: languages, glossary and the expected reply format
+fn system_prompt(
+    target_language: &str,
+    options: &TranslationOptions,
+    glossary: &[GlossaryEntry],
+) -> String {
+    let target = match &options.country {
+        Some(country) => format!("{} ({})", target_language, country),
+        None => target_language.to_string(),
+    };
+    let source = options
+        .source_language
+        .as_deref()
+        .map(|language| format!(" from {}", language))
+        .unwrap_or_default();
+
+    let mut prompt = format!(
+        "You translate video subtitles{} into {}. The user sends a JSON array of subtitle \
+         lines in playback order. Reply with only a JSON array of strings holding the \
+         translation of each line, with exactly as many elements as the input and in the \
+         same order. Keep line breaks within a line and do not merge or split lines.",
+        source, target
+    );
+
+    if !glossary.is_empty() {
+        prompt.push_str("\nAlways translate these terms as given:");
+        for entry in glossary {
+            prompt.push_str(&format!("\n{} => {}", entry.source, entry.target));
+        }
+    }
+
+    prompt
+}
+
+/// The JSON array in a model reply, ignoring code fences or text around it
+fn parse_reply(content: &str) -> Result<Vec<String>> {
+    let start = content
+        .find('[')
+        .context("Model reply contains no JSON array")?;
+    let end = content
+        .rfind(']')
+        .context("Model reply contains no JSON array")?;
+    if end < start {
+        anyhow::bail!("Model reply contains no JSON array");
+    }
+
+    serde_json::from_str(&content[start..=end]).context("Model reply is not a JSON string array")
+}
+
+impl TranslationProvider for OpenAiProvider {
+    async fn translate_cues(
+        &self,
+        cues: &[Cue],
+        target_language: &str,
+        options: &TranslationOptions,
+        glossary: &[GlossaryEntry],
+    ) -> Result<Vec<Cue>> {
+        let texts: Vec<&str> = cues.iter().map(|cue| cue.text.as_str()).collect();
+        let request_body = ChatCompletionRequest {
+            model: &self.settings.model,
+            messages: vec![
+                ChatMessage {
+                    role: "system",
+                    content: system_prompt(target_language, options, glossary),
+                },
+                ChatMessage {
+                    role: "user",
+                    content: serde_json::to_string(&texts)?,
+                },
+            ],
+            temperature: 0.0,
+        };
+
+        let client = reqwest::Client::new();
+        let mut request = client
+            .post(format!(
+                "{}/chat/completions",
+                self.settings.base_url.trim_end_matches('/')
+            ))
+            .timeout(translation_provider::REQUEST_TIMEOUT)
+            .json(&request_body);
+        if let Some(api_key) = &self.settings.api_key {
+            request = request.bearer_auth(api_key);
+        }
+
+        let response = request
+            .send()
+            .await
+            .context("Network error during chat completion request")?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_default();
+            anyhow::bail!("[HTTP {}] Chat completion failed: {}", status, error_text);
+        }
+
+        let completion: ChatCompletionResponse = response
+            .json()
+            .await
+            .context("Failed to parse chat completion response")?;
+        let content = completion
+            .choices
+            .into_iter()
+            .next()
+            .context("Chat completion returned no choices")?
+            .message
+            .content;
+
+        // A reply with the wrong number of lines counts as a failed attempt
+        translation_provider::with_texts(cues, parse_reply(&content)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_fenced_reply() {
+        let reply = "Here you go:\n```json\n[\"Bonjour\", \"Au revoir\\nà demain\"]\n```";
+        assert_eq!(
+            parse_reply(reply).unwrap(),
+            vec!["Bonjour".to_string(), "Au revoir\nà demain".to_string()]
+        );
+        assert!(parse_reply("Sorry, I can't help with that.").is_err());
+    }
+}
@@ -0,0 +1,87 @@
+/// One server-sent event
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SseEvent {
+    /// The `event:` field; None for the default `message` type
+    pub event: Option<String>,
+    /// The `data:` lines, joined with `\n`
+    pub data: String,
+}
+
+/// Incremental `text/event-stream` decoder. Network chunks may end anywhere,
+/// even inside a UTF-8 sequence, so bytes are buffered until an event is complete.
+#[derive(Debug, Default)]
+pub struct SseDecoder {
+    buffer: Vec<u8>,
+}
+
+impl SseDecoder {
+    /// Add received bytes; returns the events they completed
+    pub fn push(&mut self, chunk: &[u8]) -> Vec<SseEvent> {
+        self.buffer
+            .extend(chunk.iter().copied().filter(|byte| *byte != b'\r'));
+
+        let mut events = Vec::new();
+        while let Some(end) = self.buffer.windows(2).position(|pair| pair == b"\n\n") {
+            let block: Vec<u8> = self.buffer.drain(..end + 2).collect();
+            if let Some(event) = parse_block(&String::from_utf8_lossy(&block)) {
+                events.push(event);
+            }
+        }
+        events
+    }
+}
+
+/// Parse the lines of one event; comments and blocks without data yield nothing
+fn parse_block(block: &str) -> Option<SseEvent> {
+    let mut event = None;
+    let mut data: Vec<&str> = Vec::new();
+
+    for line in block.lines() {
+        if line.is_empty() || line.starts_with(':') {
+            continue;
+        }
+        let (field, value) = line.split_once(':').unwrap_or((line, ""));
+        let value = value.strip_prefix(' ').unwrap_or(value);
+        match field {
+            "event" => event = Some(value.to_string()),
+            "data" => data.push(value),
+            _ => {}
+        }
+    }
+
+    if data.is_empty() {
+        return None;
+    }
+    Some(SseEvent {
+        event,
+        data: data.join("\n"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_events_split_across_chunks() {
+        let mut decoder = SseDecoder::default();
+
+        assert!(decoder.push(b": keep-alive\n\nevent: sta").is_empty());
+        let events =
+            decoder.push(b"tus\r\ndata: {\"status\":\"processing\"}\r\n\r\ndata: a\ndata: b\n\n");
+
+        assert_eq!(
+            events,
+            vec![
+                SseEvent {
+                    event: Some("status".to_string()),
+                    data: "{\"status\":\"processing\"}".to_string(),
+                },
+                SseEvent {
+                    event: None,
+                    data: "a\nb".to_string(),
+                },
+            ]
+        );
+    }
+}
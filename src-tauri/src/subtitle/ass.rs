@@ -0,0 +1,408 @@
+use anyhow::Result;
+
+use super::{split_timestamp, srt, Cue, SubtitleDocument};
+
+/// Event fields assumed when `[Events]` has no `Format:` line
+const DEFAULT_EVENT_FORMAT: &[&str] = &[
+    "Layer", "Start", "End", "Style", "Name", "MarginL", "MarginR", "MarginV", "Effect", "Text",
+];
+
+/// Header used when writing ASS from cues that did not come from an ASS script
+const DEFAULT_HEADER: &str = "[Script Info]
+ScriptType: v4.00+
+PlayResX: 384
+PlayResY: 288
+WrapStyle: 0
+ScaledBorderAndShadow: yes
+
+[V4+ Styles]
+Format: Name, Fontname, Fontsize, PrimaryColour, SecondaryColour, OutlineColour, BackColour, Bold, Italic, Underline, StrikeOut, ScaleX, ScaleY, Spacing, Angle, BorderStyle, Outline, Shadow, Alignment, MarginL, MarginR, MarginV, Encoding
+Style: Default,Arial,16,&H00FFFFFF,&H000000FF,&H00000000,&H80000000,0,0,0,0,100,100,0,0,1,1,0,2,10,10,10,1
+
+[Events]
+Format: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text
+";
+
+/// A parsed ASS/SSA script.
+/// Every line is kept verbatim except the text of dialogue events, so script info,
+/// styles, fonts, comments and non-dialogue events survive a round trip.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssScript {
+    lines: Vec<AssLine>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum AssLine {
+    Raw(String),
+    Dialogue(Dialogue),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Dialogue {
+    /// Everything before the Text field, including the trailing comma
+    prefix: String,
+    start_ms: u64,
+    end_ms: u64,
+    /// Raw text with override tags and `\N` line breaks
+    text: String,
+}
+
+impl Dialogue {
+    /// Plain text of the line, or None if there is nothing to translate
+    /// (empty lines and vector drawings)
+    fn plain_text(&self) -> Option<String> {
+        if override_blocks(&self.text).any(is_drawing_block) {
+            return None;
+        }
+
+        let text = strip_override_blocks(&self.text)
+            .replace("\\N", "\n")
+            .replace("\\n", "\n")
+            .replace("\\h", " ");
+        let lines: Vec<&str> = text
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .collect();
+
+        (!lines.is_empty()).then(|| lines.join("\n"))
+    }
+}
+
+impl AssScript {
+    /// Dialogue text as cues, skipping lines with nothing to translate
+    pub fn to_document(&self) -> SubtitleDocument {
+        let cues = self
+            .translatable_dialogues()
+            .map(|(dialogue, text)| Cue::new(dialogue.start_ms, dialogue.end_ms, text))
+            .collect();
+        SubtitleDocument::new(cues)
+    }
+
+    /// Copy of the script with dialogue text replaced by `translated`, cue by cue
+    /// in the order of `to_document`. Leading override tags (position, alignment,
+    /// fades) are kept; inline tags are dropped since translation reorders words.
+    /// Dialogues without a matching cue keep their original text.
+    pub fn with_translation(&self, translated: &SubtitleDocument) -> AssScript {
+        let mut script = self.clone();
+        let mut cues = translated.cues.iter();
+
+        for line in &mut script.lines {
+            let AssLine::Dialogue(dialogue) = line else {
+                continue;
+            };
+            if dialogue.plain_text().is_none() {
+                continue;
+            }
+            let Some(cue) = cues.next() else {
+                break;
+            };
+
+            dialogue.text = format!("{}{}", leading_override_tags(&dialogue.text), cue_text(cue));
+        }
+
+        script
+    }
+
+    fn translatable_dialogues(&self) -> impl Iterator<Item = (&Dialogue, String)> {
+        self.lines.iter().filter_map(|line| match line {
+            AssLine::Dialogue(dialogue) => dialogue.plain_text().map(|text| (dialogue, text)),
+            AssLine::Raw(_) => None,
+        })
+    }
+}
+
+/// Parse an ASS or SSA script
+pub fn parse(input: &str) -> Result<AssScript> {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let normalized = input.replace("\r\n", "\n").replace('\r', "\n");
+
+    let mut lines = Vec::new();
+    let mut in_events = false;
+    let mut has_events = false;
+    let mut format: Vec<String> = DEFAULT_EVENT_FORMAT
+        .iter()
+        .map(|field| field.to_ascii_lowercase())
+        .collect();
+
+    for line in normalized.lines() {
+        let trimmed = line.trim();
+
+        if trimmed.starts_with('[') {
+            in_events = trimmed.eq_ignore_ascii_case("[events]");
+            has_events |= in_events;
+        } else if in_events {
+            if let Some(fields) = strip_key(trimmed, "Format") {
+                format = fields
+                    .split(',')
+                    .map(|field| field.trim().to_ascii_lowercase())
+                    .collect();
+            } else if strip_key(trimmed, "Dialogue").is_some() {
+                if let Some(dialogue) = parse_dialogue(line, &format) {
+                    lines.push(AssLine::Dialogue(dialogue));
+                    continue;
+                }
+            }
+        }
+
+        lines.push(AssLine::Raw(line.to_string()));
+    }
+
+    if !has_events {
+        anyhow::bail!("Not an ASS/SSA script: missing [Events] section");
+    }
+
+    Ok(AssScript { lines })
+}
+
+/// Serialize a parsed script
+pub fn to_string(script: &AssScript) -> String {
+    let mut output = String::new();
+
+    for line in &script.lines {
+        match line {
+            AssLine::Raw(raw) => output.push_str(raw),
+            AssLine::Dialogue(dialogue) => {
+                output.push_str(&dialogue.prefix);
+                output.push_str(&dialogue.text);
+            }
+        }
+        output.push('\n');
+    }
+
+    output
+}
+
+/// Serialize plain cues as an ASS script with a single default style
+pub fn from_document(document: &SubtitleDocument) -> String {
+    let mut output = String::from(DEFAULT_HEADER);
+
+    for cue in &document.cues {
+        output.push_str(&format!(
+            "Dialogue: 0,{},{},Default,,0,0,0,,{}\n",
+            format_timestamp(cue.start_ms),
+            format_timestamp(cue.end_ms),
+            cue_text(cue)
+        ));
+    }
+
+    output
+}
+
+/// Format milliseconds as an ASS timestamp (`H:MM:SS.cc`)
+pub fn format_timestamp(ms: u64) -> String {
+    let (hours, minutes, seconds, millis) = split_timestamp(ms);
+    format!("{}:{:02}:{:02}.{:02}", hours, minutes, seconds, millis / 10)
+}
+
+/// Convert cue text to ASS: SRT-style `<i>`/`<b>`/`<u>` become override tags,
+/// other markup is dropped, literal braces are escaped and newlines become `\N`
+pub fn escape_text(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(c) = rest.chars().next() {
+        match c {
+            // Existing override blocks (e.g. `{\an8}` carried over in SRT) pass through
+            '{' if rest[1..].starts_with('\\') && rest.contains('}') => {
+                let end = rest.find('}').unwrap();
+                output.push_str(&rest[..=end]);
+                rest = &rest[end + 1..];
+                continue;
+            }
+            '{' => output.push_str("\\{"),
+            '}' => output.push_str("\\}"),
+            '\n' => output.push_str("\\N"),
+            '<' => {
+                if let Some(end) = rest.find('>') {
+                    let tag = rest[1..end].trim().to_ascii_lowercase();
+                    let (closing, name) = match tag.strip_prefix('/') {
+                        Some(name) => (true, name.trim()),
+                        None => (false, tag.as_str()),
+                    };
+                    let name = name.split_whitespace().next().unwrap_or("");
+                    if matches!(name, "i" | "b" | "u") {
+                        output.push_str(&format!("{{\\{}{}}}", name, if closing { 0 } else { 1 }));
+                        rest = &rest[end + 1..];
+                        continue;
+                    }
+                    if name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+                        rest = &rest[end + 1..];
+                        continue;
+                    }
+                }
+                output.push(c);
+            }
+            _ => output.push(c),
+        }
+        rest = &rest[c.len_utf8()..];
+    }
+
+    output
+}
+
+/// Dialogue text for a cue, with any secondary lines scaled down and in italics
+fn cue_text(cue: &Cue) -> String {
+    let mut text = escape_text(&cue.text);
+    if let Some(secondary) = &cue.secondary {
+        text.push_str("\\N{\\fscx75\\fscy75\\i1}");
+        text.push_str(&escape_text(secondary));
+    }
+    text
+}
+
+/// `Key: value` with a case-insensitive key
+fn strip_key<'a>(line: &'a str, key: &str) -> Option<&'a str> {
+    let (name, value) = line.split_once(':')?;
+    name.trim().eq_ignore_ascii_case(key).then_some(value)
+}
+
+fn parse_dialogue(line: &str, format: &[String]) -> Option<Dialogue> {
+    // Text must be the last field, since it may itself contain commas
+    if format.last().map(String::as_str) != Some("text") {
+        return None;
+    }
+    let start_index = format.iter().position(|field| field == "start")?;
+    let end_index = format.iter().position(|field| field == "end")?;
+
+    let colon = line.find(':')?;
+    let body = &line[colon + 1..];
+    let fields: Vec<&str> = body.splitn(format.len(), ',').collect();
+    if fields.len() != format.len() {
+        return None;
+    }
+
+    let text = fields[format.len() - 1];
+    Some(Dialogue {
+        prefix: line[..line.len() - text.len()].to_string(),
+        start_ms: srt::parse_timestamp(fields[start_index])?,
+        end_ms: srt::parse_timestamp(fields[end_index])?,
+        text: text.to_string(),
+    })
+}
+
+/// Contents of each `{...}` block in dialogue text
+fn override_blocks(text: &str) -> impl Iterator<Item = &str> {
+    text.split('{')
+        .skip(1)
+        .filter_map(|part| part.split_once('}').map(|(block, _)| block))
+}
+
+/// `\p1`..`\p9` switch the line into vector drawing mode
+fn is_drawing_block(block: &str) -> bool {
+    block.split('\\').any(|tag| {
+        tag.strip_prefix('p')
+            .and_then(|scale| scale.parse::<u32>().ok())
+            .is_some_and(|scale| scale > 0)
+    })
+}
+
+fn strip_override_blocks(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    let mut depth = 0;
+
+    for c in text.chars() {
+        match c {
+            '{' => depth += 1,
+            '}' if depth > 0 => depth -= 1,
+            _ if depth == 0 => output.push(c),
+            _ => {}
+        }
+    }
+
+    output
+}
+
+/// Override blocks at the very start of the text, e.g. `{\pos(10,20)\an8}`
+fn leading_override_tags(text: &str) -> &str {
+    let mut end = 0;
+    while text[end..].starts_with('{') {
+        match text[end..].find('}') {
+            Some(close) => end += close + 1,
+            None => break,
+        }
+    }
+    &text[..end]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCRIPT: &str = "[Script Info]
+ScriptType: v4.00+
+
+[V4+ Styles]
+Format: Name, Fontname, Fontsize, PrimaryColour, SecondaryColour, OutlineColour, BackColour, Bold, Italic, Underline, StrikeOut, ScaleX, ScaleY, Spacing, Angle, BorderStyle, Outline, Shadow, Alignment, MarginL, MarginR, MarginV, Encoding
+Style: Sign,Arial,20,&H00FFFFFF,&H000000FF,&H00000000,&H80000000,0,0,0,0,100,100,0,0,1,1,0,8,10,10,10,1
+
+[Events]
+Format: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text
+Comment: 0,0:00:00.00,0:00:01.00,Default,,0,0,0,,Translator note
+Dialogue: 0,0:00:01.00,0:00:02.50,Default,,0,0,0,,Hello, {\\i1}world{\\i0}\\Nsecond line
+Dialogue: 1,0:00:03.00,0:00:04.00,Sign,,0,0,0,,{\\pos(100,50)\\an8}Station
+Dialogue: 0,0:00:03.00,0:00:04.00,Sign,,0,0,0,,{\\p1}m 0 0 l 10 0 10 10{\\p0}
+";
+
+    #[test]
+    fn round_trips_verbatim() {
+        let script = parse(SCRIPT).unwrap();
+        assert_eq!(to_string(&script), SCRIPT);
+    }
+
+    #[test]
+    fn extracts_dialogue_text_only() {
+        let document = parse(SCRIPT).unwrap().to_document();
+
+        assert_eq!(
+            document.cues,
+            vec![
+                Cue::new(1000, 2500, "Hello, world\nsecond line"),
+                Cue::new(3000, 4000, "Station"),
+            ]
+        );
+    }
+
+    #[test]
+    fn applies_translation_keeping_styles_and_position() {
+        let script = parse(SCRIPT).unwrap();
+        let translated = SubtitleDocument::new(vec![
+            Cue::new(1000, 2500, "Bonjour\nle monde"),
+            Cue::new(3000, 4000, "Gare"),
+        ]);
+        let output = to_string(&script.with_translation(&translated));
+
+        assert!(output.contains("Style: Sign,Arial,20,"));
+        assert!(output.contains("Comment: 0,0:00:00.00,0:00:01.00,Default,,0,0,0,,Translator note"));
+        assert!(output
+            .contains("Dialogue: 0,0:00:01.00,0:00:02.50,Default,,0,0,0,,Bonjour\\Nle monde\n"));
+        assert!(output
+            .contains("Dialogue: 1,0:00:03.00,0:00:04.00,Sign,,0,0,0,,{\\pos(100,50)\\an8}Gare\n"));
+        assert!(output.contains("{\\p1}m 0 0 l 10 0 10 10{\\p0}"));
+    }
+
+    #[test]
+    fn parses_ssa_event_format() {
+        let input = "[Script Info]\nScriptType: v4.00\n\n[Events]\nFormat: Marked, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text\nDialogue: Marked=0,0:00:01.00,0:00:02.00,Default,,0000,0000,0000,,Hi\n";
+        let document = parse(input).unwrap().to_document();
+
+        assert_eq!(document.cues, vec![Cue::new(1000, 2000, "Hi")]);
+    }
+
+    #[test]
+    fn rejects_srt_content() {
+        assert!(parse("1\n00:00:01,000 --> 00:00:02,000\nHello\n").is_err());
+    }
+
+    #[test]
+    fn writes_plain_cues_with_default_style() {
+        let document =
+            SubtitleDocument::new(vec![Cue::new(1500, 3_723_004, "<i>Hi</i> {there}\nyou")]);
+        let output = from_document(&document);
+
+        assert!(output.starts_with("[Script Info]\n"));
+        assert!(output.ends_with(
+            "Dialogue: 0,0:00:01.50,1:02:03.00,Default,,0,0,0,,{\\i1}Hi{\\i0} \\{there\\}\\Nyou\n"
+        ));
+    }
+}
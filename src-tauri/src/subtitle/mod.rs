@@ -0,0 +1,304 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+pub mod ass;
+pub mod srt;
+pub mod vtt;
+
+/// Output file format for subtitles
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SubtitleFormat {
+    Srt,
+    Vtt,
+    Ass,
+}
+
+impl SubtitleFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            SubtitleFormat::Srt => "srt",
+            SubtitleFormat::Vtt => "vtt",
+            SubtitleFormat::Ass => "ass",
+        }
+    }
+
+    pub fn render(self, document: &SubtitleDocument) -> String {
+        match self {
+            SubtitleFormat::Srt => srt::to_string(document),
+            SubtitleFormat::Vtt => vtt::to_string(document),
+            SubtitleFormat::Ass => ass::from_document(document),
+        }
+    }
+}
+
+/// Which language goes on top in bilingual output
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum BilingualOrder {
+    #[default]
+    TranslationFirst,
+    OriginalFirst,
+}
+
+/// Bilingual output: each cue carries both the translation and the original
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BilingualOptions {
+    #[serde(default)]
+    pub order: BilingualOrder,
+    /// Render the lower line smaller and in italics (VTT and ASS only)
+    #[serde(rename = "styleSecondary", default)]
+    pub style_secondary: bool,
+}
+
+fn default_allow_fallback() -> bool {
+    true
+}
+
+/// Which subtitle files to write for a translation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OutputOptions {
+    /// Empty means the input's own format
+    #[serde(
+        rename = "outputFormats",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub formats: Vec<SubtitleFormat>,
+    /// Merge original and translated cues into one file instead of translation only
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bilingual: Option<BilingualOptions>,
+    /// Write partially translated or untranslated files when chunks fail, instead of failing the task
+    #[serde(rename = "allowFallback", default = "default_allow_fallback")]
+    pub allow_fallback: bool,
+}
+
+impl Default for OutputOptions {
+    fn default() -> Self {
+        Self {
+            formats: Vec::new(),
+            bilingual: None,
+            allow_fallback: default_allow_fallback(),
+        }
+    }
+}
+
+/// Merge original and translated cues into bilingual cues, using the translated timing.
+/// Cues are paired by position when the counts match; otherwise each translated cue
+/// takes the original cues whose midpoint falls inside it.
+pub fn merge_bilingual(
+    original: &SubtitleDocument,
+    translated: &SubtitleDocument,
+    options: &BilingualOptions,
+) -> SubtitleDocument {
+    let cues = translated
+        .cues
+        .iter()
+        .enumerate()
+        .map(|(i, translated_cue)| {
+            let original_text = if original.len() == translated.len() {
+                original.cues[i].text.clone()
+            } else {
+                original
+                    .cues
+                    .iter()
+                    .filter(|cue| {
+                        let midpoint = cue.start_ms + (cue.end_ms.saturating_sub(cue.start_ms)) / 2;
+                        midpoint >= translated_cue.start_ms && midpoint < translated_cue.end_ms
+                    })
+                    .map(|cue| cue.text.as_str())
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            };
+
+            let (top, bottom) = match options.order {
+                BilingualOrder::TranslationFirst => (translated_cue.text.clone(), original_text),
+                BilingualOrder::OriginalFirst => (original_text, translated_cue.text.clone()),
+            };
+
+            let mut cue = Cue::new(translated_cue.start_ms, translated_cue.end_ms, top);
+            if bottom.trim().is_empty() || bottom == cue.text {
+                // Nothing to pair with (or the translation fell back to the original)
+            } else if options.style_secondary {
+                cue.secondary = Some(bottom);
+            } else {
+                cue.text = format!("{}\n{}", cue.text, bottom);
+            }
+            cue
+        })
+        .collect();
+
+    SubtitleDocument::new(cues)
+}
+
+/// A subtitle file as read from disk.
+/// ASS/SSA scripts are kept whole so they can be written back with their styling.
+#[derive(Debug, Clone)]
+pub enum SourceSubtitles {
+    Srt(SubtitleDocument),
+    Ass(ass::AssScript),
+}
+
+impl SourceSubtitles {
+    /// Parse subtitle file content, choosing the parser by extension
+    /// (`.ass`/`.ssa`, anything else is read as SRT)
+    pub fn parse(path: &Path, content: &str) -> Result<Self> {
+        let extension = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        match extension.as_str() {
+            "ass" | "ssa" => Ok(SourceSubtitles::Ass(ass::parse(content)?)),
+            _ => Ok(SourceSubtitles::Srt(srt::parse(content)?)),
+        }
+    }
+
+    /// The cues to translate
+    pub fn document(&self) -> SubtitleDocument {
+        match self {
+            SourceSubtitles::Srt(document) => document.clone(),
+            SourceSubtitles::Ass(script) => script.to_document(),
+        }
+    }
+
+    /// Format written when no output formats are requested
+    pub fn default_format(&self) -> SubtitleFormat {
+        match self {
+            SourceSubtitles::Srt(_) => SubtitleFormat::Srt,
+            SourceSubtitles::Ass(_) => SubtitleFormat::Ass,
+        }
+    }
+
+    /// Render `translated` cues, or the source cues if None.
+    /// ASS output from an ASS source reuses the original script and styles.
+    pub fn render(&self, format: SubtitleFormat, translated: Option<&SubtitleDocument>) -> String {
+        match (self, format, translated) {
+            (SourceSubtitles::Ass(script), SubtitleFormat::Ass, Some(document)) => {
+                ass::to_string(&script.with_translation(document))
+            }
+            (SourceSubtitles::Ass(script), SubtitleFormat::Ass, None) => ass::to_string(script),
+            (_, format, Some(document)) => format.render(document),
+            (_, format, None) => format.render(&self.document()),
+        }
+    }
+}
+
+/// A single timed subtitle entry
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cue {
+    /// Start time in milliseconds
+    pub start_ms: u64,
+    /// End time in milliseconds
+    pub end_ms: u64,
+    /// Cue text, lines separated by `\n`
+    pub text: String,
+    /// Second-language lines shown below `text` in bilingual output.
+    /// VTT and ASS render them smaller and in italics; SRT appends them as plain lines.
+    pub secondary: Option<String>,
+}
+
+impl Cue {
+    pub fn new(start_ms: u64, end_ms: u64, text: impl Into<String>) -> Self {
+        Self {
+            start_ms,
+            end_ms,
+            text: text.into(),
+            secondary: None,
+        }
+    }
+
+    pub fn lines(&self) -> impl Iterator<Item = &str> {
+        self.text.lines()
+    }
+
+    pub fn secondary_lines(&self) -> impl Iterator<Item = &str> {
+        self.secondary.iter().flat_map(|text| text.lines())
+    }
+}
+
+/// An ordered list of cues, independent of the file format it came from
+/// Cue numbering is positional; serializers renumber from 1
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SubtitleDocument {
+    pub cues: Vec<Cue>,
+}
+
+impl SubtitleDocument {
+    pub fn new(cues: Vec<Cue>) -> Self {
+        Self { cues }
+    }
+
+    pub fn len(&self) -> usize {
+        self.cues.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cues.is_empty()
+    }
+}
+
+/// Split a millisecond offset into (hours, minutes, seconds, millis)
+pub(crate) fn split_timestamp(ms: u64) -> (u64, u64, u64, u64) {
+    (
+        ms / 3_600_000,
+        (ms / 60_000) % 60,
+        (ms / 1000) % 60,
+        ms % 1000,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn document(cues: &[(u64, u64, &str)]) -> SubtitleDocument {
+        SubtitleDocument::new(
+            cues.iter()
+                .map(|(start, end, text)| Cue::new(*start, *end, *text))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn merges_by_position_translation_first() {
+        let original = document(&[(0, 1000, "Hello"), (1000, 2000, "Bye")]);
+        let translated = document(&[(0, 1000, "Bonjour"), (1000, 2000, "Salut")]);
+        let merged = merge_bilingual(&original, &translated, &BilingualOptions::default());
+
+        assert_eq!(merged.cues[0].text, "Bonjour\nHello");
+        assert_eq!(merged.cues[1].text, "Salut\nBye");
+        assert_eq!(merged.cues[1].secondary, None);
+    }
+
+    #[test]
+    fn merges_by_timing_when_counts_differ() {
+        let original = document(&[(0, 1000, "One"), (1000, 2000, "Two"), (3000, 4000, "Three")]);
+        let translated = document(&[(0, 2000, "Un deux"), (3000, 4000, "Trois")]);
+        let options = BilingualOptions {
+            order: BilingualOrder::OriginalFirst,
+            style_secondary: true,
+        };
+        let merged = merge_bilingual(&original, &translated, &options);
+
+        assert_eq!(merged.cues[0].text, "One\nTwo");
+        assert_eq!(merged.cues[0].secondary.as_deref(), Some("Un deux"));
+        assert_eq!(merged.cues[1].text, "Three");
+        assert_eq!(merged.cues[1].secondary.as_deref(), Some("Trois"));
+    }
+
+    #[test]
+    fn renders_secondary_lines() {
+        let mut cue = Cue::new(0, 1000, "Bonjour");
+        cue.secondary = Some("Hello".to_string());
+        let document = SubtitleDocument::new(vec![cue]);
+
+        assert!(srt::to_string(&document).contains("Bonjour\nHello\n"));
+        let vtt = vtt::to_string(&document);
+        assert!(vtt.contains("::cue(.secondary)"));
+        assert!(vtt.contains("Bonjour\n<c.secondary>Hello</c>\n"));
+        assert!(ass::from_document(&document).contains(",,Bonjour\\N{\\fscx75\\fscy75\\i1}Hello\n"));
+    }
+}
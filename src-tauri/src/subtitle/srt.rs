@@ -0,0 +1,219 @@
+use anyhow::Result;
+
+use super::{split_timestamp, Cue, SubtitleDocument};
+
+/// Parse SRT text into a document.
+/// Tolerates a UTF-8 BOM, CRLF/CR line endings, missing blank lines between cues,
+/// missing or wrong cue numbers, and `.` instead of `,` before the milliseconds.
+/// Fails only when the input has content but no recognisable cue.
+pub fn parse(input: &str) -> Result<SubtitleDocument> {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let normalized = input.replace("\r\n", "\n").replace('\r', "\n");
+
+    let mut cues: Vec<Cue> = Vec::new();
+    let mut previous_line: Option<&str> = None;
+
+    for line in normalized.lines() {
+        let trimmed = line.trim();
+
+        if let Some((start_ms, end_ms)) = parse_timing_line(trimmed) {
+            // A bare number right before the timing line is the cue index, not text
+            // of the previous cue (happens when the blank separator line is missing)
+            if let Some(previous) = previous_line {
+                if is_cue_number(previous) {
+                    if let Some(cue) = cues.last_mut() {
+                        strip_last_line(&mut cue.text, previous);
+                    }
+                }
+            }
+
+            cues.push(Cue::new(start_ms, end_ms, String::new()));
+        } else if !trimmed.is_empty() {
+            // Text before the first timing line is ignored (usually the first index)
+            if let Some(cue) = cues.last_mut() {
+                if !cue.text.is_empty() {
+                    cue.text.push('\n');
+                }
+                cue.text.push_str(line.trim_end());
+            }
+        }
+
+        previous_line = Some(trimmed);
+    }
+
+    if cues.is_empty() && !normalized.trim().is_empty() {
+        anyhow::bail!("No subtitle cues found in SRT content");
+    }
+
+    Ok(SubtitleDocument::new(cues))
+}
+
+/// Serialize a document as SRT, numbering cues from 1
+pub fn to_string(document: &SubtitleDocument) -> String {
+    let mut output = String::new();
+
+    for (i, cue) in document.cues.iter().enumerate() {
+        output.push_str(&format!(
+            "{}\n{} --> {}\n",
+            i + 1,
+            format_timestamp(cue.start_ms),
+            format_timestamp(cue.end_ms)
+        ));
+        // Blank lines inside the text would end the cue early
+        for line in cue
+            .lines()
+            .chain(cue.secondary_lines())
+            .filter(|line| !line.trim().is_empty())
+        {
+            output.push_str(line);
+            output.push('\n');
+        }
+        output.push('\n');
+    }
+
+    output
+}
+
+/// Format milliseconds as an SRT timestamp (`HH:MM:SS,mmm`)
+pub fn format_timestamp(ms: u64) -> String {
+    let (hours, minutes, seconds, millis) = split_timestamp(ms);
+    format!("{:02}:{:02}:{:02},{:03}", hours, minutes, seconds, millis)
+}
+
+/// Parse a timestamp like `00:01:02,345`, `0:01:02.3` or `01:02,345`
+pub fn parse_timestamp(value: &str) -> Option<u64> {
+    let value = value.trim();
+    let (clock, fraction) = match value.rsplit_once([',', '.']) {
+        Some((clock, fraction)) => (clock, fraction),
+        None => (value, ""),
+    };
+
+    let parts: Vec<&str> = clock.split(':').collect();
+    let (hours, minutes, seconds) = match parts.as_slice() {
+        [h, m, s] => (parse_digits(h)?, parse_digits(m)?, parse_digits(s)?),
+        [m, s] => (0, parse_digits(m)?, parse_digits(s)?),
+        _ => return None,
+    };
+
+    // Fractions are decimal: ",5" is 500ms, ",0500" is rounded down to 50ms
+    let millis = if fraction.is_empty() {
+        0
+    } else {
+        let digits: String = fraction.chars().chain("000".chars()).take(3).collect();
+        parse_digits(&digits)?
+    };
+
+    if minutes >= 60 || seconds >= 60 {
+        return None;
+    }
+
+    Some(((hours * 60 + minutes) * 60 + seconds) * 1000 + millis)
+}
+
+/// Parse `start --> end`, ignoring any trailing position coordinates
+fn parse_timing_line(line: &str) -> Option<(u64, u64)> {
+    let (start, rest) = line.split_once("-->")?;
+    let end = rest.split_whitespace().next()?;
+    Some((parse_timestamp(start)?, parse_timestamp(end)?))
+}
+
+fn parse_digits(value: &str) -> Option<u64> {
+    if value.is_empty() || !value.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    value.parse().ok()
+}
+
+fn is_cue_number(line: &str) -> bool {
+    parse_digits(line).is_some()
+}
+
+/// Remove `line` from the end of a cue's text, if it is the last line
+fn strip_last_line(text: &mut String, line: &str) {
+    let last_line_start = text.rfind('\n').map(|i| i + 1).unwrap_or(0);
+    if text[last_line_start..].trim() == line {
+        text.truncate(last_line_start.saturating_sub(1));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_well_formed_srt() {
+        let input = "1\n00:00:01,000 --> 00:00:02,500\nHello\n\n2\n00:00:03,000 --> 00:00:04,000\nTwo\nlines\n";
+        let document = parse(input).unwrap();
+
+        assert_eq!(
+            document.cues,
+            vec![
+                Cue::new(1000, 2500, "Hello"),
+                Cue::new(3000, 4000, "Two\nlines"),
+            ]
+        );
+    }
+
+    #[test]
+    fn handles_bom_and_crlf() {
+        let input = "\u{feff}1\r\n00:00:01,000 --> 00:00:02,000\r\nHello\r\n\r\n2\r\n00:00:02,000 --> 00:00:03,000\r\nWorld\r\n";
+        let document = parse(input).unwrap();
+
+        assert_eq!(document.len(), 2);
+        assert_eq!(document.cues[0].text, "Hello");
+        assert_eq!(document.cues[1].text, "World");
+    }
+
+    #[test]
+    fn handles_missing_blank_lines() {
+        let input = "1\n00:00:01,000 --> 00:00:02,000\nHello\n2\n00:00:03,000 --> 00:00:04,000\nWorld\n00:00:05,000 --> 00:00:06,000\nNo index\n";
+        let document = parse(input).unwrap();
+
+        assert_eq!(
+            document.cues,
+            vec![
+                Cue::new(1000, 2000, "Hello"),
+                Cue::new(3000, 4000, "World"),
+                Cue::new(5000, 6000, "No index"),
+            ]
+        );
+    }
+
+    #[test]
+    fn accepts_period_and_short_millis() {
+        let input = "1\n00:00:01.5 --> 0:00:02.250 X1:10 X2:20\nHello\n";
+        let document = parse(input).unwrap();
+
+        assert_eq!(document.cues, vec![Cue::new(1500, 2250, "Hello")]);
+    }
+
+    #[test]
+    fn empty_input_is_empty_document() {
+        assert!(parse("").unwrap().is_empty());
+        assert!(parse("\u{feff}\n\n").unwrap().is_empty());
+    }
+
+    #[test]
+    fn rejects_content_without_cues() {
+        assert!(parse("this is not a subtitle file").is_err());
+    }
+
+    #[test]
+    fn serializes_and_renumbers() {
+        let document = SubtitleDocument::new(vec![
+            Cue::new(0, 1500, "First"),
+            Cue::new(3_723_004, 3_725_000, "Second\n\nwith gap"),
+        ]);
+
+        assert_eq!(
+            to_string(&document),
+            "1\n00:00:00,000 --> 00:00:01,500\nFirst\n\n2\n01:02:03,004 --> 01:02:05,000\nSecond\nwith gap\n\n"
+        );
+    }
+
+    #[test]
+    fn round_trips() {
+        let input = "1\n00:00:01,000 --> 00:00:02,000\nHello\n\n2\n00:10:00,123 --> 00:10:01,456\n<i>World</i>\n\n";
+        assert_eq!(to_string(&parse(input).unwrap()), input);
+    }
+}
@@ -0,0 +1,139 @@
+use super::{split_timestamp, SubtitleDocument};
+
+/// Tags WebVTT renders the same way SRT players do
+const SUPPORTED_TAGS: &[&str] = &["i", "b", "u"];
+
+/// Style block for the lower line of bilingual cues
+const SECONDARY_STYLE: &str =
+    "STYLE\n::cue(.secondary) {\n  font-size: 75%;\n  font-style: italic;\n}\n\n";
+
+/// Serialize a document as WebVTT
+pub fn to_string(document: &SubtitleDocument) -> String {
+    let mut output = String::from("WEBVTT\n\n");
+
+    if document.cues.iter().any(|cue| cue.secondary.is_some()) {
+        output.push_str(SECONDARY_STYLE);
+    }
+
+    for (i, cue) in document.cues.iter().enumerate() {
+        output.push_str(&format!(
+            "{}\n{} --> {}\n",
+            i + 1,
+            format_timestamp(cue.start_ms),
+            format_timestamp(cue.end_ms)
+        ));
+        // Blank lines inside the text would end the cue early
+        for line in cue.lines().filter(|line| !line.trim().is_empty()) {
+            output.push_str(&escape_text(line));
+            output.push('\n');
+        }
+        for line in cue.secondary_lines().filter(|line| !line.trim().is_empty()) {
+            output.push_str(&format!("<c.secondary>{}</c>\n", escape_text(line)));
+        }
+        output.push('\n');
+    }
+
+    output
+}
+
+/// Format milliseconds as a WebVTT timestamp (`HH:MM:SS.mmm`)
+pub fn format_timestamp(ms: u64) -> String {
+    let (hours, minutes, seconds, millis) = split_timestamp(ms);
+    format!("{:02}:{:02}:{:02}.{:03}", hours, minutes, seconds, millis)
+}
+
+/// Escape cue text for WebVTT.
+/// Keeps `<i>`, `<b>` and `<u>`, drops other HTML-like tags (e.g. SRT `<font>`)
+/// and ASS override blocks like `{\an8}`, and escapes `&`, `<` and `>`.
+pub fn escape_text(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(c) = rest.chars().next() {
+        if c == '<' {
+            if let Some(end) = rest.find('>') {
+                let tag = &rest[1..end];
+                if let Some(name) = tag_name(tag) {
+                    if SUPPORTED_TAGS.contains(&name.as_str()) {
+                        let slash = if tag.trim_start().starts_with('/') {
+                            "/"
+                        } else {
+                            ""
+                        };
+                        output.push_str(&format!("<{}{}>", slash, name));
+                    }
+                    rest = &rest[end + 1..];
+                    continue;
+                }
+            }
+        } else if c == '{' && rest[1..].starts_with('\\') {
+            if let Some(end) = rest.find('}') {
+                rest = &rest[end + 1..];
+                continue;
+            }
+        }
+
+        match c {
+            '&' => output.push_str("&amp;"),
+            '<' => output.push_str("&lt;"),
+            '>' => output.push_str("&gt;"),
+            _ => output.push(c),
+        }
+        rest = &rest[c.len_utf8()..];
+    }
+
+    output
+}
+
+/// Lowercased element name of `tag` (the text between `<` and `>`), if it looks like markup
+fn tag_name(tag: &str) -> Option<String> {
+    let tag = tag.trim();
+    let tag = tag.strip_prefix('/').unwrap_or(tag);
+    let name: String = tag
+        .chars()
+        .take_while(|c| c.is_ascii_alphanumeric())
+        .collect();
+
+    let is_markup = name.starts_with(|c: char| c.is_ascii_alphabetic())
+        && tag[name.len()..]
+            .chars()
+            .next()
+            .is_none_or(|c| c.is_whitespace() || c == '=');
+
+    is_markup.then(|| name.to_ascii_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subtitle::Cue;
+
+    #[test]
+    fn writes_header_and_timestamps() {
+        let document = SubtitleDocument::new(vec![
+            Cue::new(1500, 3_723_004, "Hello"),
+            Cue::new(4000, 5000, "Two\nlines"),
+        ]);
+
+        assert_eq!(
+            to_string(&document),
+            "WEBVTT\n\n1\n00:00:01.500 --> 01:02:03.004\nHello\n\n2\n00:00:04.000 --> 00:00:05.000\nTwo\nlines\n\n"
+        );
+    }
+
+    #[test]
+    fn escapes_text() {
+        assert_eq!(escape_text("Tom & Jerry"), "Tom &amp; Jerry");
+        assert_eq!(escape_text("a --> b"), "a --&gt; b");
+        assert_eq!(escape_text("1 < 2 > 0"), "1 &lt; 2 &gt; 0");
+    }
+
+    #[test]
+    fn keeps_supported_tags_and_drops_others() {
+        assert_eq!(
+            escape_text("<I>Hi</I> <font color=\"red\">there</font>"),
+            "<i>Hi</i> there"
+        );
+        assert_eq!(escape_text("{\\an8}Top"), "Top");
+    }
+}
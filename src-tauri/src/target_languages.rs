@@ -0,0 +1,28 @@
+use serde::Deserialize;
+
+/// One target language or a list of them, as accepted by the batch commands
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum TargetLanguages {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl TargetLanguages {
+    /// The languages in order, without blanks or duplicates
+    pub fn into_vec(self) -> Vec<String> {
+        let languages = match self {
+            TargetLanguages::One(language) => vec![language],
+            TargetLanguages::Many(languages) => languages,
+        };
+
+        let mut unique: Vec<String> = Vec::new();
+        for language in languages {
+            let language = language.trim().to_string();
+            if !language.is_empty() && !unique.contains(&language) {
+                unique.push(language);
+            }
+        }
+        unique
+    }
+}
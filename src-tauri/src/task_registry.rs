@@ -0,0 +1,90 @@
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use tokio_util::sync::CancellationToken;
+
+// Global registry of cancellation tokens for running tasks, keyed by task id
+static TASK_REGISTRY: Lazy<Mutex<HashMap<String, CancellationToken>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Error returned by pipeline stages when a task's cancellation token fires
+#[derive(Debug, Clone, Copy)]
+pub struct TaskCancelled;
+
+impl std::fmt::Display for TaskCancelled {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Task was cancelled")
+    }
+}
+
+impl std::error::Error for TaskCancelled {}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TaskCancelledPayload {
+    #[serde(rename = "taskId")]
+    pub task_id: String,
+}
+
+/// Register a task and return its cancellation token.
+/// Returns None if a task with this id is already registered, so a second run can't
+/// take over (and later unregister) the token of the one still in progress.
+pub fn register(task_id: &str) -> Option<CancellationToken> {
+    let mut registry = TASK_REGISTRY.lock().unwrap();
+    if registry.contains_key(task_id) {
+        return None;
+    }
+    let token = CancellationToken::new();
+    registry.insert(task_id.to_string(), token.clone());
+    Some(token)
+}
+
+/// Remove a finished task from the registry
+pub fn unregister(task_id: &str) {
+    TASK_REGISTRY.lock().unwrap().remove(task_id);
+}
+
+/// Check whether a task is currently registered (queued or running)
+pub fn is_registered(task_id: &str) -> bool {
+    TASK_REGISTRY.lock().unwrap().contains_key(task_id)
+}
+
+/// Cancel a registered task. Returns false if no task with this id is running.
+pub fn cancel(task_id: &str) -> bool {
+    match TASK_REGISTRY.lock().unwrap().get(task_id) {
+        Some(token) => {
+            token.cancel();
+            true
+        }
+        None => false,
+    }
+}
+
+/// Check whether an error (or any error in its context chain) is a cancellation
+pub fn is_cancelled(error: &anyhow::Error) -> bool {
+    error.chain().any(|cause| cause.is::<TaskCancelled>())
+}
+
+/// Run a future until it completes or the token is cancelled.
+/// Dropping the future on cancellation aborts any in-flight request it owns.
+pub async fn run_cancellable<F, T>(cancel_token: &CancellationToken, future: F) -> anyhow::Result<T>
+where
+    F: std::future::Future<Output = anyhow::Result<T>>,
+{
+    tokio::select! {
+        result = future => result,
+        _ = cancel_token.cancelled() => Err(TaskCancelled.into()),
+    }
+}
+
+/// Sleep for the given duration, returning early with an error if the token is cancelled
+pub async fn sleep_cancellable(
+    cancel_token: &CancellationToken,
+    duration: std::time::Duration,
+) -> anyhow::Result<()> {
+    run_cancellable(cancel_token, async {
+        tokio::time::sleep(duration).await;
+        Ok(())
+    })
+    .await
+}
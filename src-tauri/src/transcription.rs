@@ -0,0 +1,795 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+use std::ops::ControlFlow;
+use std::path::Path;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Manager, Window};
+use tokio_util::sync::CancellationToken;
+
+use crate::audio_split::{self, AudioChunk};
+use crate::backend_transcription::{self, BackendProvider};
+use crate::ffmpeg::AudioCodec;
+use crate::job_store::{self, JobStage};
+use crate::subtitle::srt;
+use crate::task_registry::{self, TaskCancelled};
+use crate::whisper_cpp::{WhisperCppProvider, WhisperCppSettings};
+
+// Polling starts at a provider's poll interval and backs off by POLL_BACKOFF_FACTOR
+const POLL_INTERVAL_SECS: u64 = 2;
+const MAX_POLL_INTERVAL_SECS: u64 = 30;
+const POLL_BACKOFF_FACTOR: f64 = 1.5;
+const MAX_WAIT_SECS: u64 = 1800; // 30 minutes max per job
+const STATUS_EVENT_IDLE_SECS: u64 = 90; // Longer silences on an event stream fall back to polling
+
+/// Status updates pushed by a provider, see `TranscriptionProvider::subscribe`
+pub type StatusReceiver = tokio::sync::mpsc::Receiver<Result<JobStatus>>;
+
+/// Which service turns the extracted audio into the original-language SRT
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum TranscriptionProviderConfig {
+    /// The transcription backend at the batch's backend URL
+    #[default]
+    Backend,
+    /// A local whisper.cpp binary and model; audio never leaves the machine
+    WhisperCpp(WhisperCppSettings),
+}
+
+impl TranscriptionProviderConfig {
+    /// Codec to extract the audio in; whisper.cpp only reads WAV
+    pub fn audio_codec(&self, requested: AudioCodec) -> AudioCodec {
+        match self {
+            TranscriptionProviderConfig::Backend => requested,
+            TranscriptionProviderConfig::WhisperCpp(_) => AudioCodec::Wav,
+        }
+    }
+}
+
+/// State of a submitted transcription job
+#[derive(Debug, Clone)]
+pub enum JobStatus {
+    /// Still queued or running; `status` is the provider's own wording
+    Pending {
+        status: String,
+    },
+    Completed {
+        language_code: Option<String>,
+    },
+    Failed {
+        message: String,
+    },
+}
+
+/// A service that transcribes an audio file into SRT in three steps:
+/// submit the audio, poll the job until it completes, then fetch the SRT
+pub trait TranscriptionProvider: Sync {
+    /// Shown in the task log
+    fn name(&self) -> &str;
+
+    /// Whether a job outlives the app, so a persisted job ID can be polled after a restart
+    fn is_resumable(&self) -> bool;
+
+    /// Delay before the first status poll; later polls back off from it
+    fn poll_interval(&self) -> Duration {
+        Duration::from_secs(POLL_INTERVAL_SECS)
+    }
+
+    /// Subscribe to pushed status updates for a job, if the provider offers them.
+    /// None (the default) makes the caller poll instead.
+    fn subscribe(
+        &self,
+        _job_id: &str,
+    ) -> impl Future<Output = Result<Option<StatusReceiver>>> + Send {
+        async { Ok(None) }
+    }
+
+    /// Start transcribing `audio_path`; returns the job ID
+    fn submit(
+        &self,
+        audio_path: &str,
+        task_id: &str,
+        window: &Window,
+        app_handle: &AppHandle,
+        cancel_token: &CancellationToken,
+    ) -> impl Future<Output = Result<String>> + Send;
+
+    /// Check a job's status once
+    fn poll(
+        &self,
+        job_id: &str,
+        task_id: &str,
+        window: &Window,
+        app_handle: &AppHandle,
+        cancel_token: &CancellationToken,
+    ) -> impl Future<Output = Result<JobStatus>> + Send;
+
+    /// SRT content of a completed job
+    fn fetch_result(
+        &self,
+        job_id: &str,
+        task_id: &str,
+        window: &Window,
+        app_handle: &AppHandle,
+        cancel_token: &CancellationToken,
+    ) -> impl Future<Output = Result<String>> + Send;
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TranscriptionStartedPayload {
+    #[serde(rename = "taskId")]
+    pub task_id: String,
+    #[serde(rename = "transcriptId")]
+    pub transcript_id: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TranscriptionPollingPayload {
+    #[serde(rename = "taskId")]
+    pub task_id: String,
+    pub status: String,
+}
+
+/// Payload for transcription complete event
+#[derive(Debug, Clone, Serialize)]
+pub struct TranscriptionCompletePayload {
+    #[serde(rename = "taskId")]
+    pub task_id: String,
+    /// Path to the temporary audio file (will be cleaned up)
+    #[serde(rename = "audioPath")]
+    pub audio_path: String,
+    /// Path to the final SRT transcript file
+    #[serde(rename = "transcriptPath")]
+    pub transcript_path: String,
+    /// Language detected by the provider, if it reported one
+    #[serde(rename = "languageCode", skip_serializing_if = "Option::is_none")]
+    pub language_code: Option<String>,
+}
+
+/// Original-language SRT produced by a transcription provider
+#[derive(Debug, Clone)]
+pub struct Transcript {
+    /// Path to the original SRT in the temp directory
+    pub srt_path: String,
+    /// Language detected by the provider, e.g. "en"
+    pub language_code: Option<String>,
+}
+
+/// Check that the configured provider can run before any task starts
+pub async fn validate_provider(config: &TranscriptionProviderConfig) -> Result<()> {
+    match config {
+        TranscriptionProviderConfig::Backend => Ok(()),
+        TranscriptionProviderConfig::WhisperCpp(settings) => settings.validate().await,
+    }
+}
+
+/// Main transcription orchestration function
+/// Returns the original SRT in the temp directory (for translation) and the detected language
+#[allow(clippy::too_many_arguments)]
+pub async fn transcribe_audio(
+    config: &TranscriptionProviderConfig,
+    backend_url: &str,
+    task_id: &str,
+    audio_path: &str,
+    original_file_path: &str,
+    window: &Window,
+    app_handle: &AppHandle,
+    cancel_token: &CancellationToken,
+) -> Result<Transcript> {
+    match config {
+        TranscriptionProviderConfig::Backend => {
+            let provider = BackendProvider::new(backend_url);
+            let file_size = tokio::fs::metadata(audio_path)
+                .await
+                .context("Failed to read audio file metadata")?
+                .len();
+
+            // Audio over the upload limit is transcribed in pieces
+            if file_size > backend_transcription::MAX_FILE_SIZE_BYTES {
+                transcribe_in_chunks(
+                    &provider,
+                    backend_transcription::MAX_FILE_SIZE_BYTES,
+                    task_id,
+                    audio_path,
+                    original_file_path,
+                    window,
+                    app_handle,
+                    cancel_token,
+                )
+                .await
+            } else {
+                transcribe_with(
+                    &provider,
+                    task_id,
+                    audio_path,
+                    original_file_path,
+                    window,
+                    app_handle,
+                    cancel_token,
+                )
+                .await
+            }
+        }
+        TranscriptionProviderConfig::WhisperCpp(settings) => {
+            transcribe_with(
+                &WhisperCppProvider::new(settings.clone()),
+                task_id,
+                audio_path,
+                original_file_path,
+                window,
+                app_handle,
+                cancel_token,
+            )
+            .await
+        }
+    }
+}
+
+/// Re-attach to a transcription job created before the app was restarted
+/// Returns the original SRT in the temp directory (for translation) and the detected language
+#[allow(clippy::too_many_arguments)]
+pub async fn resume_transcription(
+    config: &TranscriptionProviderConfig,
+    backend_url: &str,
+    task_id: &str,
+    job_id: &str,
+    audio_path: &str,
+    original_file_path: &str,
+    window: &Window,
+    app_handle: &AppHandle,
+    cancel_token: &CancellationToken,
+) -> Result<Transcript> {
+    crate::logger::append_log_entry(
+        app_handle,
+        window,
+        task_id,
+        "transcription",
+        &format!("Resuming existing transcription job (Job ID: {})", job_id),
+    )
+    .await?;
+
+    match config {
+        TranscriptionProviderConfig::Backend => {
+            wait_for_transcript(
+                &BackendProvider::new(backend_url),
+                task_id,
+                job_id,
+                audio_path,
+                original_file_path,
+                window,
+                app_handle,
+                cancel_token,
+            )
+            .await
+        }
+        // Local jobs die with the app and never persist a job ID
+        TranscriptionProviderConfig::WhisperCpp(_) => {
+            anyhow::bail!("whisper.cpp jobs cannot be resumed (Job ID: {})", job_id)
+        }
+    }
+}
+
+/// Submit the audio to `provider` and wait for its transcript
+async fn transcribe_with<P: TranscriptionProvider>(
+    provider: &P,
+    task_id: &str,
+    audio_path: &str,
+    original_file_path: &str,
+    window: &Window,
+    app_handle: &AppHandle,
+    cancel_token: &CancellationToken,
+) -> Result<Transcript> {
+    crate::logger::append_log_entry(
+        app_handle,
+        window,
+        task_id,
+        "transcription",
+        &format!(
+            "Starting transcription for: {} (provider: {})",
+            audio_path,
+            provider.name()
+        ),
+    )
+    .await?;
+
+    // Step 1: Submit audio and create transcription job
+    let job_id = provider
+        .submit(audio_path, task_id, window, app_handle, cancel_token)
+        .await?;
+
+    // Persist the job ID so a restarted app can re-attach instead of re-uploading
+    let resumable = provider.is_resumable();
+    let _ = job_store::update_job(app_handle, task_id, |record| {
+        record.stage = JobStage::Transcribing;
+        record.job_id = resumable.then(|| job_id.clone());
+    })
+    .await;
+
+    wait_for_transcript(
+        provider,
+        task_id,
+        &job_id,
+        audio_path,
+        original_file_path,
+        window,
+        app_handle,
+        cancel_token,
+    )
+    .await
+}
+
+/// Split the audio into chunks under `max_bytes`, transcribe each as its own job and
+/// stitch the SRTs back together. All chunks are submitted before polling so the
+/// provider can work on them in parallel. Chunk jobs are not persisted for resuming;
+/// a restarted task transcribes the audio again.
+#[allow(clippy::too_many_arguments)]
+async fn transcribe_in_chunks<P: TranscriptionProvider>(
+    provider: &P,
+    max_bytes: u64,
+    task_id: &str,
+    audio_path: &str,
+    original_file_path: &str,
+    window: &Window,
+    app_handle: &AppHandle,
+    cancel_token: &CancellationToken,
+) -> Result<Transcript> {
+    let chunks = audio_split::split_audio(
+        audio_path,
+        max_bytes,
+        task_id,
+        window,
+        app_handle,
+        cancel_token,
+    )
+    .await?;
+
+    let result = transcribe_chunks(
+        provider,
+        &chunks,
+        task_id,
+        audio_path,
+        original_file_path,
+        window,
+        app_handle,
+        cancel_token,
+    )
+    .await;
+    audio_split::remove_chunks(&chunks).await;
+
+    result
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn transcribe_chunks<P: TranscriptionProvider>(
+    provider: &P,
+    chunks: &[AudioChunk],
+    task_id: &str,
+    audio_path: &str,
+    original_file_path: &str,
+    window: &Window,
+    app_handle: &AppHandle,
+    cancel_token: &CancellationToken,
+) -> Result<Transcript> {
+    let mut job_ids = Vec::new();
+    for (index, chunk) in chunks.iter().enumerate() {
+        crate::logger::append_log_entry(
+            app_handle,
+            window,
+            task_id,
+            "transcription",
+            &format!(
+                "Submitting chunk {}/{}: {} (provider: {})",
+                index + 1,
+                chunks.len(),
+                chunk.path,
+                provider.name()
+            ),
+        )
+        .await?;
+
+        let job_id = provider
+            .submit(&chunk.path, task_id, window, app_handle, cancel_token)
+            .await
+            .with_context(|| format!("Chunk {}/{} upload failed", index + 1, chunks.len()))?;
+        job_ids.push(job_id);
+    }
+
+    let _ = job_store::update_job(app_handle, task_id, |record| {
+        record.stage = JobStage::Transcribing;
+        record.job_id = None;
+    })
+    .await;
+
+    window
+        .emit(
+            "transcription:started",
+            TranscriptionStartedPayload {
+                task_id: task_id.to_string(),
+                transcript_id: job_ids.join(","),
+            },
+        )
+        .context("Failed to emit transcription:started event")?;
+
+    let mut parts = Vec::new();
+    let mut language_code = None;
+    for (chunk, job_id) in chunks.iter().zip(&job_ids) {
+        let chunk_language =
+            poll_until_complete(provider, job_id, task_id, window, app_handle, cancel_token)
+                .await?;
+        language_code = language_code.or(chunk_language);
+
+        let srt_content = provider
+            .fetch_result(job_id, task_id, window, app_handle, cancel_token)
+            .await?;
+        let document = srt::parse(&srt_content)
+            .with_context(|| format!("Invalid SRT for chunk (Job ID: {})", job_id))?;
+        parts.push((document, chunk.offset_ms));
+    }
+
+    let stitched = audio_split::stitch(parts);
+    crate::logger::append_log_entry(
+        app_handle,
+        window,
+        task_id,
+        "transcription",
+        &format!(
+            "Stitched {} chunk transcripts into {} cues",
+            chunks.len(),
+            stitched.len()
+        ),
+    )
+    .await?;
+
+    save_transcript(
+        &srt::to_string(&stitched),
+        language_code,
+        &job_ids.join(", "),
+        task_id,
+        audio_path,
+        original_file_path,
+        window,
+        app_handle,
+    )
+    .await
+}
+
+/// Build the temp path for the original SRT of a task
+/// (named after the ORIGINAL file, not the temp audio file)
+fn temp_srt_path_for(
+    app_handle: &AppHandle,
+    task_id: &str,
+    original_file_path: &str,
+) -> Result<String> {
+    let original_file = Path::new(original_file_path);
+    let file_stem = original_file
+        .file_stem()
+        .context("Failed to get file name")?
+        .to_str()
+        .context("Invalid file name")?;
+
+    // Create temp directory for original SRT (will be translated later)
+    let temp_dir = app_handle
+        .path()
+        .temp_dir()
+        .context("Failed to get temp directory")?;
+
+    let srt_temp_dir = temp_dir.join("translation-app-srt");
+    std::fs::create_dir_all(&srt_temp_dir).context("Failed to create SRT temp directory")?;
+
+    // Save with -original.srt suffix in temp folder
+    let temp_srt_path = srt_temp_dir.join(format!("{}_{}-original.srt", task_id, file_stem));
+    let temp_srt_path_str = temp_srt_path
+        .to_str()
+        .context("Invalid temp SRT path")?
+        .to_string();
+
+    Ok(temp_srt_path_str)
+}
+
+/// Log a status update and emit `transcription:polling`
+/// Breaks with the detected language once the job has completed.
+async fn report_status(
+    status: JobStatus,
+    label: &str,
+    job_id: &str,
+    task_id: &str,
+    window: &Window,
+    app_handle: &AppHandle,
+) -> Result<ControlFlow<Option<String>>> {
+    let status_text = match &status {
+        JobStatus::Pending { status } => status.as_str(),
+        JobStatus::Completed { .. } => "completed",
+        JobStatus::Failed { .. } => "error",
+    };
+
+    // Log status
+    crate::logger::append_log_entry(
+        app_handle,
+        window,
+        task_id,
+        "transcription",
+        &format!("{}: Status = {} (Job ID: {})", label, status_text, job_id),
+    )
+    .await?;
+
+    // Emit polling event
+    window
+        .emit(
+            "transcription:polling",
+            TranscriptionPollingPayload {
+                task_id: task_id.to_string(),
+                status: status_text.to_string(),
+            },
+        )
+        .ok();
+
+    match status {
+        JobStatus::Completed { language_code } => {
+            crate::logger::append_log_entry(
+                app_handle,
+                window,
+                task_id,
+                "transcription",
+                &format!(
+                    "Transcription completed successfully! (Job ID: {}, detected language: {})",
+                    job_id,
+                    language_code.as_deref().unwrap_or("unknown")
+                ),
+            )
+            .await?;
+            Ok(ControlFlow::Break(language_code))
+        }
+        JobStatus::Failed { message } => {
+            anyhow::bail!("Transcription failed (Job ID: {}): {}", job_id, message);
+        }
+        JobStatus::Pending { .. } => Ok(ControlFlow::Continue(())),
+    }
+}
+
+/// Follow the provider's status events until the job finishes
+/// Returns None if the stream ends, errors or goes quiet first, so the caller can poll.
+#[allow(clippy::too_many_arguments)]
+async fn follow_status_events(
+    mut receiver: StatusReceiver,
+    deadline: Instant,
+    job_id: &str,
+    task_id: &str,
+    window: &Window,
+    app_handle: &AppHandle,
+    cancel_token: &CancellationToken,
+) -> Result<Option<Option<String>>> {
+    let mut events = 0;
+
+    loop {
+        if Instant::now() >= deadline {
+            anyhow::bail!(
+                "Transcription timeout: no result within {} minutes (Job ID: {})",
+                MAX_WAIT_SECS / 60,
+                job_id
+            );
+        }
+
+        let idle_timeout = Duration::from_secs(STATUS_EVENT_IDLE_SECS);
+        let next = tokio::select! {
+            next = tokio::time::timeout(idle_timeout, receiver.recv()) => next,
+            _ = cancel_token.cancelled() => return Err(TaskCancelled.into()),
+        };
+
+        let reason = match next {
+            Ok(Some(Ok(status))) => {
+                events += 1;
+                let label = format!("Status event {}", events);
+                match report_status(status, &label, job_id, task_id, window, app_handle).await? {
+                    ControlFlow::Break(language_code) => return Ok(Some(language_code)),
+                    ControlFlow::Continue(()) => continue,
+                }
+            }
+            Ok(Some(Err(e))) => format!("{:#}", e),
+            Ok(None) => "stream closed".to_string(),
+            Err(_) => format!("no event for {}s", STATUS_EVENT_IDLE_SECS),
+        };
+
+        crate::logger::append_log_entry(
+            app_handle,
+            window,
+            task_id,
+            "transcription",
+            &format!(
+                "Status events stopped ({}), polling instead (Job ID: {})",
+                reason, job_id
+            ),
+        )
+        .await?;
+        return Ok(None);
+    }
+}
+
+/// Wait for a job to complete or fail, following the provider's status events when it
+/// offers them and otherwise polling with a delay that grows from `poll_interval`
+/// up to MAX_POLL_INTERVAL_SECS, so short jobs finish quickly and long ones cost few requests
+/// Returns the language detected by the provider, if any
+async fn poll_until_complete<P: TranscriptionProvider>(
+    provider: &P,
+    job_id: &str,
+    task_id: &str,
+    window: &Window,
+    app_handle: &AppHandle,
+    cancel_token: &CancellationToken,
+) -> Result<Option<String>> {
+    let deadline = Instant::now() + Duration::from_secs(MAX_WAIT_SECS);
+
+    match task_registry::run_cancellable(cancel_token, provider.subscribe(job_id)).await {
+        Ok(Some(receiver)) => {
+            crate::logger::append_log_entry(
+                app_handle,
+                window,
+                task_id,
+                "transcription",
+                &format!("Following status events (Job ID: {})", job_id),
+            )
+            .await?;
+
+            let finished = follow_status_events(
+                receiver,
+                deadline,
+                job_id,
+                task_id,
+                window,
+                app_handle,
+                cancel_token,
+            )
+            .await?;
+            if let Some(language_code) = finished {
+                return Ok(language_code);
+            }
+        }
+        Ok(None) => {}
+        Err(e) if task_registry::is_cancelled(&e) => return Err(e),
+        Err(e) => {
+            crate::logger::append_log_entry(
+                app_handle,
+                window,
+                task_id,
+                "transcription",
+                &format!(
+                    "Status events unavailable ({:#}), polling instead (Job ID: {})",
+                    e, job_id
+                ),
+            )
+            .await?;
+        }
+    }
+
+    let mut attempts = 0;
+    let mut interval = provider.poll_interval();
+
+    loop {
+        if Instant::now() >= deadline {
+            anyhow::bail!(
+                "Transcription timeout: no result within {} minutes (Job ID: {})",
+                MAX_WAIT_SECS / 60,
+                job_id
+            );
+        }
+
+        task_registry::sleep_cancellable(cancel_token, interval).await?;
+        attempts += 1;
+        interval = interval
+            .mul_f64(POLL_BACKOFF_FACTOR)
+            .min(Duration::from_secs(MAX_POLL_INTERVAL_SECS));
+
+        let status = provider
+            .poll(job_id, task_id, window, app_handle, cancel_token)
+            .await?;
+
+        let label = format!("Poll attempt {}", attempts);
+        if let ControlFlow::Break(language_code) =
+            report_status(status, &label, job_id, task_id, window, app_handle).await?
+        {
+            return Ok(language_code);
+        }
+    }
+}
+
+/// Poll an existing job until complete and save its SRT to the temp folder
+#[allow(clippy::too_many_arguments)]
+async fn wait_for_transcript<P: TranscriptionProvider>(
+    provider: &P,
+    task_id: &str,
+    job_id: &str,
+    audio_path: &str,
+    original_file_path: &str,
+    window: &Window,
+    app_handle: &AppHandle,
+    cancel_token: &CancellationToken,
+) -> Result<Transcript> {
+    // Emit transcription started event AFTER logs are written
+    window
+        .emit(
+            "transcription:started",
+            TranscriptionStartedPayload {
+                task_id: task_id.to_string(),
+                transcript_id: job_id.to_string(),
+            },
+        )
+        .context("Failed to emit transcription:started event")?;
+
+    // Step 2: Poll until complete
+    let language_code =
+        poll_until_complete(provider, job_id, task_id, window, app_handle, cancel_token).await?;
+
+    // Step 3: Fetch the SRT and save it to the temp folder
+    let srt_content = provider
+        .fetch_result(job_id, task_id, window, app_handle, cancel_token)
+        .await?;
+
+    save_transcript(
+        &srt_content,
+        language_code,
+        job_id,
+        task_id,
+        audio_path,
+        original_file_path,
+        window,
+        app_handle,
+    )
+    .await
+}
+
+/// Write a finished transcript to the temp folder and emit `transcription:complete`
+#[allow(clippy::too_many_arguments)]
+async fn save_transcript(
+    srt_content: &str,
+    language_code: Option<String>,
+    job_id: &str,
+    task_id: &str,
+    audio_path: &str,
+    original_file_path: &str,
+    window: &Window,
+    app_handle: &AppHandle,
+) -> Result<Transcript> {
+    let temp_srt_path_str = temp_srt_path_for(app_handle, task_id, original_file_path)?;
+
+    tokio::fs::write(&temp_srt_path_str, srt_content)
+        .await
+        .context("Failed to write SRT file")?;
+
+    crate::logger::append_log_entry(
+        app_handle,
+        window,
+        task_id,
+        "transcription",
+        &format!(
+            "Original SRT file saved to temp: {} (Job ID: {})",
+            temp_srt_path_str, job_id
+        ),
+    )
+    .await?;
+
+    crate::logger::append_log_entry(
+        app_handle,
+        window,
+        task_id,
+        "transcription",
+        "Transcription completed! Original SRT ready for translation.",
+    )
+    .await?;
+
+    // Emit transcription complete event
+    window
+        .emit(
+            "transcription:complete",
+            TranscriptionCompletePayload {
+                task_id: task_id.to_string(),
+                audio_path: audio_path.to_string(),
+                transcript_path: temp_srt_path_str.clone(),
+                language_code: language_code.clone(),
+            },
+        )
+        .context("Failed to emit transcription:complete event")?;
+
+    Ok(Transcript {
+        srt_path: temp_srt_path_str,
+        language_code,
+    })
+}
@@ -1,28 +1,43 @@
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::ops::Range;
 use std::path::Path;
+use std::sync::Arc;
 use std::time::Duration;
 use tauri::{AppHandle, Emitter, Window};
+use tokio::sync::Semaphore;
+use tokio_util::sync::CancellationToken;
+
+use crate::glossary::{self, GlossaryEntry};
+use crate::libretranslate::LibreTranslateProvider;
+use crate::openai_translation::OpenAiProvider;
+use crate::subtitle::{
+    self, srt, Cue, OutputOptions, SourceSubtitles, SubtitleDocument, SubtitleFormat,
+};
+use crate::translation_memory::{self, MemoryKey};
+use crate::translation_provider::{
+    ServerProvider, TranslationOptions, TranslationProvider, TranslationProviderConfig,
+};
+use crate::translation_status::TranslationStatus;
+use crate::{job_store, task_registry};
 
 const MAX_RETRIES: u32 = 3;
 const INITIAL_RETRY_DELAY_MS: u64 = 1000; // Start with 1 second
 
-#[derive(Debug, Serialize)]
-struct TranslationRequest {
-    srt_content: String,
-    target_language: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    source_language: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    country: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    model: Option<String>,
-}
+// Long files are translated in chunks so a single request stays within server limits
+const CHUNK_SIZE: usize = 150; // Cues translated per request
+const CHUNK_CONTEXT: usize = 5; // Preceding cues sent along for context, then discarded
+const MAX_CONCURRENT_CHUNKS: usize = 3; // Chunk requests in flight across a whole batch
+const MAX_CONCURRENT_LANGUAGES: usize = 3; // Target languages of one file translated at a time
 
-#[derive(Debug, Deserialize)]
-struct TranslationResponse {
-    translated_srt: String,
-    entry_count: i32,
+/// Per-batch options of `translate_srt_batch`
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct SrtBatchOptions {
+    #[serde(flatten)]
+    pub output: OutputOptions,
+    #[serde(flatten)]
+    pub translation: TranslationOptions,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -31,23 +46,129 @@ pub struct TranslationStartedPayload {
     pub task_id: String,
     #[serde(rename = "originalSrtPath")]
     pub original_srt_path: String,
+    #[serde(rename = "targetLanguage")]
+    pub target_language: String,
+}
+
+/// Files written for one target language
+#[derive(Debug, Clone)]
+pub struct TranslationOutput {
+    pub language: String,
+    pub status: TranslationStatus,
+    /// One file per output format, in the requested order
+    pub files: Vec<(SubtitleFormat, String)>,
+}
+
+impl TranslationOutput {
+    pub fn paths(&self) -> Vec<String> {
+        self.files.iter().map(|(_, path)| path.clone()).collect()
+    }
+
+    /// The file written in `format`, if it was requested
+    pub fn path_for(&self, format: SubtitleFormat) -> Option<&str> {
+        self.files
+            .iter()
+            .find(|(file_format, _)| *file_format == format)
+            .map(|(_, path)| path.as_str())
+    }
+}
+
+/// Where one chunk's cues are, in the document sent and in the translated document
+#[derive(Debug, Clone, PartialEq)]
+struct ChunkSpan {
+    source: Range<usize>,
+    translated: Range<usize>,
+    /// The chunk failed every retry and kept its original cues
+    failed: bool,
+}
+
+/// Result of a chunked translation; chunks that failed every retry keep their original cues
+struct ChunkedTranslation {
+    document: SubtitleDocument,
+    failed_chunks: usize,
+    total_chunks: usize,
+    /// One span per chunk, in order
+    spans: Vec<ChunkSpan>,
+    /// Error of the first failed chunk
+    error: Option<anyhow::Error>,
+}
+
+/// Payload for `translation:fallback`, emitted when a language is not fully translated
+#[derive(Debug, Clone, Serialize)]
+pub struct TranslationFallbackPayload {
+    #[serde(rename = "taskId")]
+    pub task_id: String,
+    #[serde(rename = "targetLanguage")]
+    pub target_language: String,
+    pub status: TranslationStatus,
+    #[serde(rename = "failedChunks")]
+    pub failed_chunks: usize,
+    #[serde(rename = "totalChunks")]
+    pub total_chunks: usize,
+    pub error: String,
+    #[serde(rename = "outputPaths")]
+    pub output_paths: Vec<String>,
 }
 
+/// Payload for `translation:progress`, emitted as each chunk of a language finishes
+#[derive(Debug, Clone, Serialize)]
+pub struct TranslationProgressPayload {
+    #[serde(rename = "taskId")]
+    pub task_id: String,
+    #[serde(rename = "targetLanguage")]
+    pub target_language: String,
+    #[serde(rename = "completedChunks")]
+    pub completed_chunks: usize,
+    #[serde(rename = "totalChunks")]
+    pub total_chunks: usize,
+}
+
+/// Payload for `translation:language-complete`, emitted once per target language
+#[derive(Debug, Clone, Serialize)]
+pub struct TranslationLanguageCompletePayload {
+    #[serde(rename = "taskId")]
+    pub task_id: String,
+    #[serde(rename = "targetLanguage")]
+    pub target_language: String,
+    pub status: TranslationStatus,
+    /// Every file written for this language, one per requested output format
+    #[serde(rename = "outputPaths")]
+    pub output_paths: Vec<String>,
+    /// CSV of cues missing a glossary term, if any were found
+    #[serde(rename = "qaReportPath", skip_serializing_if = "Option::is_none")]
+    pub qa_report_path: Option<String>,
+}
+
+/// Payload for `translation:complete`, emitted once all target languages are done
 #[derive(Debug, Clone, Serialize)]
 pub struct TranslationCompletePayload {
     #[serde(rename = "taskId")]
     pub task_id: String,
+    /// First written output of the first target language
     #[serde(rename = "translatedSrtPath")]
     pub translated_srt_path: String,
+    /// Combined status of all target languages
+    pub status: TranslationStatus,
+    /// Every file written, for all target languages
+    #[serde(rename = "outputPaths")]
+    pub output_paths: Vec<String>,
+}
+
+/// Slots for chunk requests, shared by every file and language of one batch
+/// so the translation service never sees more than MAX_CONCURRENT_CHUNKS at once
+pub fn chunk_slots() -> Arc<Semaphore> {
+    Arc::new(Semaphore::new(MAX_CONCURRENT_CHUNKS))
 }
 
 /// Retry a function with exponential backoff
+/// Cancelling the token aborts the in-flight attempt and skips any remaining retries
 async fn retry_with_backoff<F, Fut, T>(
     mut operation: F,
     operation_name: &str,
     task_id: &str,
     window: &Window,
     app_handle: &AppHandle,
+    cancel_token: &CancellationToken,
 ) -> Result<T>
 where
     F: FnMut() -> Fut,
@@ -56,8 +177,9 @@ where
     let mut last_error = None;
 
     for attempt in 0..MAX_RETRIES {
-        match operation().await {
+        match task_registry::run_cancellable(cancel_token, operation()).await {
             Ok(result) => return Ok(result),
+            Err(e) if task_registry::is_cancelled(&e) => return Err(e),
             Err(e) => {
                 last_error = Some(e);
 
@@ -80,7 +202,8 @@ where
                     )
                     .await;
 
-                    tokio::time::sleep(Duration::from_millis(delay)).await;
+                    task_registry::sleep_cancellable(cancel_token, Duration::from_millis(delay))
+                        .await?;
                 }
             }
         }
@@ -90,9 +213,275 @@ where
     Err(last_error.unwrap())
 }
 
+/// Split `len` cues into consecutive ranges of at most CHUNK_SIZE cues
+fn plan_chunks(len: usize) -> Vec<Range<usize>> {
+    (0..len)
+        .step_by(CHUNK_SIZE)
+        .map(|start| start..(start + CHUNK_SIZE).min(len))
+        .collect()
+}
+
+/// Drop the translated context cues from a chunk response.
+/// Cues are matched by position when the provider kept the cue count, otherwise by timing.
+fn strip_context_cues(sent: &[Cue], context_len: usize, mut cues: Vec<Cue>) -> Result<Vec<Cue>> {
+    if cues.len() == sent.len() {
+        return Ok(cues.split_off(context_len));
+    }
+
+    let first_own_start = sent[context_len].start_ms;
+    let own_cues: Vec<Cue> = cues
+        .into_iter()
+        .filter(|cue| cue.start_ms >= first_own_start)
+        .collect();
+    if own_cues.is_empty() {
+        anyhow::bail!("Translated chunk contains no cues after its context");
+    }
+
+    Ok(own_cues)
+}
+
+/// Translate a document with the provider selected in `options`
+#[allow(clippy::too_many_arguments)]
+async fn translate_with_provider(
+    server_url: &str,
+    task_id: &str,
+    document: &SubtitleDocument,
+    target_language: &str,
+    options: &TranslationOptions,
+    glossary_terms: &[GlossaryEntry],
+    chunk_slots: &Arc<Semaphore>,
+    window: &Window,
+    app_handle: &AppHandle,
+    cancel_token: &CancellationToken,
+) -> Result<ChunkedTranslation> {
+    match &options.provider {
+        TranslationProviderConfig::Server => {
+            translate_document(
+                Arc::new(ServerProvider::new(server_url)),
+                task_id,
+                document,
+                target_language,
+                options,
+                glossary_terms,
+                chunk_slots,
+                window,
+                app_handle,
+                cancel_token,
+            )
+            .await
+        }
+        TranslationProviderConfig::OpenAiCompatible(settings) => {
+            translate_document(
+                Arc::new(OpenAiProvider::new(settings.clone())),
+                task_id,
+                document,
+                target_language,
+                options,
+                glossary_terms,
+                chunk_slots,
+                window,
+                app_handle,
+                cancel_token,
+            )
+            .await
+        }
+        TranslationProviderConfig::LibreTranslate(settings) => {
+            // Unsupported languages fail here, before any chunk is sent and retried
+            let provider = LibreTranslateProvider::new(
+                settings.clone(),
+                options.source_language.as_deref(),
+                target_language,
+            )?;
+            translate_document(
+                Arc::new(provider),
+                task_id,
+                document,
+                target_language,
+                options,
+                glossary_terms,
+                chunk_slots,
+                window,
+                app_handle,
+                cancel_token,
+            )
+            .await
+        }
+    }
+}
+
+/// Translate a document in chunks of CHUNK_SIZE cues, each holding one of the batch's chunk slots.
+/// Each chunk carries the CHUNK_CONTEXT preceding cues so lines are not translated in
+/// isolation, and is retried on its own. Chunks are reassembled in order; a chunk that
+/// fails every retry keeps its original cues. Only cancellation is returned as an error.
+#[allow(clippy::too_many_arguments)]
+async fn translate_document<P: TranslationProvider>(
+    provider: Arc<P>,
+    task_id: &str,
+    document: &SubtitleDocument,
+    target_language: &str,
+    options: &TranslationOptions,
+    glossary_terms: &[GlossaryEntry],
+    chunk_slots: &Arc<Semaphore>,
+    window: &Window,
+    app_handle: &AppHandle,
+    cancel_token: &CancellationToken,
+) -> Result<ChunkedTranslation> {
+    let chunks = plan_chunks(document.len());
+    let total_chunks = chunks.len();
+
+    if total_chunks > 1 {
+        crate::logger::append_log_entry(
+            app_handle,
+            window,
+            task_id,
+            "translation",
+            &format!(
+                "Translating {} cues to {} in {} chunks",
+                document.len(),
+                target_language,
+                total_chunks
+            ),
+        )
+        .await?;
+    }
+
+    let mut join_set = tokio::task::JoinSet::new();
+
+    for (chunk_index, range) in chunks.iter().cloned().enumerate() {
+        let context_start = range.start.saturating_sub(CHUNK_CONTEXT);
+        let context_len = range.start - context_start;
+        let sent: Vec<Cue> = document.cues[context_start..range.end].to_vec();
+        let chunk_terms = glossary::terms_in(&sent, glossary_terms);
+
+        let chunk_slots = chunk_slots.clone();
+        let provider = provider.clone();
+        let task_id = task_id.to_string();
+        let target_language = target_language.to_string();
+        let options = options.clone();
+        let window = window.clone();
+        let app_handle = app_handle.clone();
+        let cancel_token = cancel_token.clone();
+
+        join_set.spawn(async move {
+            let _permit = chunk_slots.acquire_owned().await;
+            let operation_name = format!("Translation chunk {}/{}", chunk_index + 1, total_chunks);
+
+            let result = retry_with_backoff(
+                || {
+                    let provider = &provider;
+                    let target_language = &target_language;
+                    let sent = &sent;
+                    let options = &options;
+                    let chunk_terms = &chunk_terms;
+                    async move {
+                        let translated = provider
+                            .translate_cues(sent, target_language, options, chunk_terms)
+                            .await?;
+                        strip_context_cues(sent, context_len, translated)
+                    }
+                },
+                &operation_name,
+                &task_id,
+                &window,
+                &app_handle,
+                &cancel_token,
+            )
+            .await
+            .with_context(|| format!("{} failed", operation_name));
+
+            (chunk_index, result)
+        });
+    }
+
+    let mut translated_chunks: Vec<Option<Vec<Cue>>> = vec![None; total_chunks];
+    let mut failed = vec![false; total_chunks];
+    let mut completed_chunks = 0;
+    let mut failed_chunks = 0;
+    let mut first_error = None;
+
+    while let Some(joined) = join_set.join_next().await {
+        let (chunk_index, result) = joined.context("Translation chunk task panicked")?;
+        match result {
+            Ok(cues) => translated_chunks[chunk_index] = Some(cues),
+            // Returning early drops the set, which aborts the remaining chunks
+            Err(e) if task_registry::is_cancelled(&e) => return Err(e),
+            Err(e) => {
+                crate::logger::append_log_entry(
+                    app_handle,
+                    window,
+                    task_id,
+                    "error",
+                    &format!("{}. Keeping its original text.", e),
+                )
+                .await?;
+
+                failed_chunks += 1;
+                failed[chunk_index] = true;
+                first_error.get_or_insert(e);
+                translated_chunks[chunk_index] =
+                    Some(document.cues[chunks[chunk_index].clone()].to_vec());
+            }
+        }
+        completed_chunks += 1;
+
+        let _ = window.emit(
+            "translation:progress",
+            TranslationProgressPayload {
+                task_id: task_id.to_string(),
+                target_language: target_language.to_string(),
+                completed_chunks,
+                total_chunks,
+            },
+        );
+    }
+
+    // A chunk may come back with a different cue count, so its place in the
+    // translated document is only known once the chunks before it are in
+    let mut cues: Vec<Cue> = Vec::new();
+    let mut spans = Vec::new();
+    for ((source, chunk_cues), failed) in chunks.into_iter().zip(translated_chunks).zip(failed) {
+        let start = cues.len();
+        cues.extend(chunk_cues.unwrap_or_default());
+        spans.push(ChunkSpan {
+            source,
+            translated: start..cues.len(),
+            failed,
+        });
+    }
+
+    Ok(ChunkedTranslation {
+        document: SubtitleDocument::new(cues),
+        failed_chunks,
+        total_chunks,
+        spans,
+        error: first_error,
+    })
+}
+
+/// Write translated cues (or the source cues if None) once per output format
+async fn write_outputs(
+    output_paths: &[(SubtitleFormat, String)],
+    source: &SourceSubtitles,
+    document: Option<&SubtitleDocument>,
+) -> Result<()> {
+    for (format, path) in output_paths {
+        tokio::fs::write(path, source.render(*format, document))
+            .await
+            .with_context(|| format!("Failed to write {}", path))?;
+    }
+
+    Ok(())
+}
+
 /// Main translation function
-/// If translation fails, copies original SRT to output folder as fallback
+/// Reads SRT or ASS/SSA input (by extension); only dialogue text is translated
+/// Writes one file per entry in `output.formats` (the input's format if empty) and returns their paths
+/// In bilingual mode each output cue holds both the translation and the original
+/// If some or all chunks fail, writes `_partial`/`_untranslated` files and emits
+/// `translation:fallback`, or returns an error if `output.allow_fallback` is off
+/// (cancellation is returned as an error instead, without writing any output)
 /// Always includes language suffix in output filename (e.g., video_zh.srt)
+#[allow(clippy::too_many_arguments)]
 pub async fn translate_srt(
     server_url: &str,
     task_id: &str,
@@ -100,9 +489,13 @@ pub async fn translate_srt(
     target_language: &str,
     output_folder: &str,
     original_file_path: &str,
+    output: &OutputOptions,
+    options: &TranslationOptions,
+    chunk_slots: &Arc<Semaphore>,
     window: &Window,
     app_handle: &AppHandle,
-) -> Result<String> {
+    cancel_token: &CancellationToken,
+) -> Result<TranslationOutput> {
     // Get the base filename from the ORIGINAL video file
     let original_file = Path::new(original_file_path);
     let file_stem = original_file
@@ -111,16 +504,6 @@ pub async fn translate_srt(
         .to_str()
         .context("Invalid file name")?;
 
-    // Create final output path with language suffix (e.g., video_zh.srt, subtitle_en.srt)
-    // This allows users to translate the same file to multiple languages without overwrites
-    let sanitized_language = target_language.replace(" ", "_");
-    let final_srt_path =
-        Path::new(output_folder).join(format!("{}_{}.srt", file_stem, sanitized_language));
-    let final_srt_path_str = final_srt_path
-        .to_str()
-        .context("Invalid final SRT output path")?
-        .to_string();
-
     crate::logger::append_log_entry(
         app_handle,
         window,
@@ -137,14 +520,72 @@ pub async fn translate_srt(
             TranslationStartedPayload {
                 task_id: task_id.to_string(),
                 original_srt_path: original_srt_path.to_string(),
+                target_language: target_language.to_string(),
             },
         )
         .context("Failed to emit translation:started event")?;
 
-    // Read the original SRT content
-    let srt_content = tokio::fs::read_to_string(original_srt_path)
+    // Read the original subtitles; the server always receives the cues as normalized SRT
+    let original_content = tokio::fs::read_to_string(original_srt_path)
         .await
-        .context("Failed to read original SRT file")?;
+        .context("Failed to read original subtitle file")?;
+    let source = SourceSubtitles::parse(Path::new(original_srt_path), &original_content)
+        .context("Failed to parse original subtitle file")?;
+    let original_document = source.document();
+    let glossary_terms =
+        load_glossary_terms(options, target_language, task_id, window, app_handle).await?;
+
+    // Cues already in the translation memory are not sent again
+    let memory_key = MemoryKey::new(
+        options.source_language.as_deref(),
+        target_language,
+        options.country.as_deref(),
+        options.provider.memory_model(options).as_deref(),
+        &glossary::fingerprint(&glossary_terms),
+    );
+    let cached =
+        lookup_memory(&memory_key, &original_document, task_id, window, app_handle).await?;
+    let uncached_document = SubtitleDocument::new(
+        original_document
+            .cues
+            .iter()
+            .filter(|cue| !cached.contains_key(&cue.text))
+            .cloned()
+            .collect(),
+    );
+
+    let mut formats: Vec<SubtitleFormat> = Vec::new();
+    for format in &output.formats {
+        if !formats.contains(format) {
+            formats.push(*format);
+        }
+    }
+    if formats.is_empty() {
+        formats.push(source.default_format());
+    }
+
+    // Create final output paths with language suffix (e.g., video_zh.srt, subtitle_en.vtt)
+    // This allows users to translate the same file to multiple languages without overwrites.
+    // Files that are not fully translated get a marker so they can't pass for translations.
+    let sanitized_language = target_language.replace(" ", "_");
+    let output_paths_with = |marker: &str| -> Result<Vec<(SubtitleFormat, String)>> {
+        let mut output_paths = Vec::new();
+        for format in &formats {
+            let path = Path::new(output_folder).join(format!(
+                "{}_{}{}.{}",
+                file_stem,
+                sanitized_language,
+                marker,
+                format.extension()
+            ));
+            let path_str = path
+                .to_str()
+                .context("Invalid final subtitle output path")?
+                .to_string();
+            output_paths.push((*format, path_str));
+        }
+        Ok(output_paths)
+    };
 
     crate::logger::append_log_entry(
         app_handle,
@@ -152,114 +593,757 @@ pub async fn translate_srt(
         task_id,
         "translation",
         &format!(
-            "Sending SRT to translation server: {} (target: {})",
-            server_url, target_language
+            "Sending cues to {} ({} cues, source: {}, target: {})",
+            options.provider.describe(server_url),
+            uncached_document.len(),
+            options.source_language.as_deref().unwrap_or("auto"),
+            target_language
         ),
     )
     .await?;
 
-    // Attempt translation with retry logic
-    let translation_result = retry_with_backoff(
-        || {
-            let server_url = server_url.to_string();
-            let srt_content = srt_content.clone();
-            let target_language = target_language.to_string();
-            async move {
-                let client = reqwest::Client::new();
-                let request_body = TranslationRequest {
-                    srt_content,
-                    target_language,
-                    source_language: None,
-                    country: None,
-                    model: None,
-                };
-
-                let response = client
-                    .post(format!("{}/translate", server_url))
-                    .header("Content-Type", "application/json")
-                    .json(&request_body)
-                    .send()
-                    .await
-                    .context("Network error during translation request")?;
-
-                if !response.status().is_success() {
-                    let status = response.status();
-                    let error_text = response.text().await.unwrap_or_default();
-                    anyhow::bail!("[HTTP {}] Translation failed: {}", status, error_text);
-                }
-
-                let translation_response: TranslationResponse = response
-                    .json()
-                    .await
-                    .context("Failed to parse translation response")?;
+    // Translate chunk by chunk, retrying failed chunks (nothing to send for an empty
+    // transcript or when every cue is cached)
+    let translation_result = if uncached_document.is_empty() {
+        Ok(ChunkedTranslation {
+            document: SubtitleDocument::default(),
+            failed_chunks: 0,
+            total_chunks: 0,
+            spans: Vec::new(),
+            error: None,
+        })
+    } else {
+        translate_with_provider(
+            server_url,
+            task_id,
+            &uncached_document,
+            target_language,
+            options,
+            &glossary_terms,
+            chunk_slots,
+            window,
+            app_handle,
+            cancel_token,
+        )
+        .await
+    };
 
-                Ok(translation_response)
-            }
+    let translation = match translation_result {
+        Ok(translation) => translation,
+        // Cancelled by the user - don't write a fallback file
+        Err(e) if task_registry::is_cancelled(&e) => return Err(e),
+        Err(e) => ChunkedTranslation {
+            document: uncached_document.clone(),
+            failed_chunks: 1,
+            total_chunks: 1,
+            spans: vec![ChunkSpan {
+                source: 0..uncached_document.len(),
+                translated: 0..uncached_document.len(),
+                failed: true,
+            }],
+            error: Some(e),
         },
-        "Translation",
+    };
+
+    // Remember the new translations, then put cached and new cues back together in order
+    remember_translations(
+        &memory_key,
+        &uncached_document,
+        &translation,
         task_id,
         window,
         app_handle,
     )
-    .await;
+    .await?;
+    let translation = ChunkedTranslation {
+        document: merge_cached(&original_document, &cached, translation.document),
+        ..translation
+    };
 
-    // Handle translation result with fallback
-    match translation_result {
-        Ok(response) => {
-            // Translation succeeded - save translated SRT
-            tokio::fs::write(&final_srt_path_str, &response.translated_srt)
-                .await
-                .context("Failed to write translated SRT file")?;
+    // Cached cues are translated even if every request failed
+    let status = if translation.failed_chunks == 0 {
+        TranslationStatus::Translated
+    } else if translation.failed_chunks < translation.total_chunks || !cached.is_empty() {
+        TranslationStatus::Partial
+    } else {
+        TranslationStatus::Fallback
+    };
+    let error_message = translation
+        .error
+        .as_ref()
+        .map(|e| format!("{:#}", e))
+        .unwrap_or_default();
 
+    if status != TranslationStatus::Translated && !output.allow_fallback {
+        anyhow::bail!(
+            "{} of {} chunks could not be translated and fallback is disabled: {}",
+            translation.failed_chunks,
+            translation.total_chunks,
+            error_message
+        );
+    }
+
+    let output_paths = match status {
+        TranslationStatus::Translated => output_paths_with("")?,
+        TranslationStatus::Partial => output_paths_with("_partial")?,
+        TranslationStatus::Fallback => output_paths_with("_untranslated")?,
+    };
+    let written_paths: Vec<String> = output_paths.iter().map(|(_, path)| path.clone()).collect();
+
+    let mut qa_report_path = None;
+    if status == TranslationStatus::Fallback {
+        // Nothing was translated - write the original subtitles as they were
+        crate::logger::append_log_entry(
+            app_handle,
+            window,
+            task_id,
+            "error",
+            &format!(
+                "Translation failed: {}. Falling back to original subtitles.",
+                error_message
+            ),
+        )
+        .await?;
+
+        write_outputs(&output_paths, &source, None)
+            .await
+            .context("Failed to write original subtitles as fallback")?;
+
+        crate::logger::append_log_entry(
+            app_handle,
+            window,
+            task_id,
+            "metadata",
+            &format!("Original subtitles saved to: {}", written_paths.join(", ")),
+        )
+        .await?;
+    } else {
+        let translated_document = translation.document;
+        if translated_document.len() != original_document.len() {
             crate::logger::append_log_entry(
                 app_handle,
                 window,
                 task_id,
                 "translation",
                 &format!(
-                    "Translation complete: {} entries translated, saved to {}",
-                    response.entry_count, final_srt_path_str
+                    "Warning: Translated SRT has {} cues, original has {}",
+                    translated_document.len(),
+                    original_document.len()
                 ),
             )
             .await?;
         }
+
+        let translated_document_len = translated_document.len();
+        let output_document = match &output.bilingual {
+            Some(bilingual) => {
+                subtitle::merge_bilingual(&original_document, &translated_document, bilingual)
+            }
+            None => translated_document.clone(),
+        };
+
+        write_outputs(&output_paths, &source, Some(&output_document))
+            .await
+            .context("Failed to write translated subtitle file")?;
+
+        let message = if status == TranslationStatus::Partial {
+            format!(
+                "Translation partially complete: {} of {} chunks failed and kept their original text, saved to {}",
+                translation.failed_chunks,
+                translation.total_chunks,
+                written_paths.join(", ")
+            )
+        } else {
+            format!(
+                "Translation complete: {} entries translated, saved to {}",
+                translated_document_len,
+                written_paths.join(", ")
+            )
+        };
+        crate::logger::append_log_entry(app_handle, window, task_id, "translation", &message)
+            .await?;
+
+        if !glossary_terms.is_empty() {
+            let report_path = Path::new(output_folder).join(format!(
+                "{}_{}_glossary_qa.csv",
+                file_stem, sanitized_language
+            ));
+            qa_report_path = check_glossary(
+                task_id,
+                &original_document,
+                &translated_document,
+                &glossary_terms,
+                &report_path,
+                window,
+                app_handle,
+            )
+            .await?;
+        }
+    }
+
+    if status != TranslationStatus::Translated {
+        window
+            .emit(
+                "translation:fallback",
+                TranslationFallbackPayload {
+                    task_id: task_id.to_string(),
+                    target_language: target_language.to_string(),
+                    status,
+                    failed_chunks: translation.failed_chunks,
+                    total_chunks: translation.total_chunks,
+                    error: error_message,
+                    output_paths: written_paths.clone(),
+                },
+            )
+            .context("Failed to emit translation:fallback event")?;
+    }
+
+    // Emit language complete event (whether translated or fallback)
+    window
+        .emit(
+            "translation:language-complete",
+            TranslationLanguageCompletePayload {
+                task_id: task_id.to_string(),
+                target_language: target_language.to_string(),
+                status,
+                output_paths: written_paths.clone(),
+                qa_report_path,
+            },
+        )
+        .context("Failed to emit translation:language-complete event")?;
+
+    Ok(TranslationOutput {
+        language: target_language.to_string(),
+        status,
+        files: output_paths,
+    })
+}
+
+/// Cached translations of the document's cues, keyed by source text
+/// A memory that cannot be read is logged and the document is translated in full
+async fn lookup_memory(
+    key: &MemoryKey,
+    document: &SubtitleDocument,
+    task_id: &str,
+    window: &Window,
+    app_handle: &AppHandle,
+) -> Result<HashMap<String, String>> {
+    let texts: Vec<String> = document.cues.iter().map(|cue| cue.text.clone()).collect();
+
+    match translation_memory::lookup(app_handle, key, texts).await {
+        Ok(cached) => {
+            if !cached.is_empty() {
+                let cached_cues = document
+                    .cues
+                    .iter()
+                    .filter(|cue| cached.contains_key(&cue.text))
+                    .count();
+                crate::logger::append_log_entry(
+                    app_handle,
+                    window,
+                    task_id,
+                    "translation",
+                    &format!(
+                        "Translation memory: {} of {} cues already translated",
+                        cached_cues,
+                        document.len()
+                    ),
+                )
+                .await?;
+            }
+            Ok(cached)
+        }
         Err(e) => {
-            // Translation failed - fallback to original SRT
             crate::logger::append_log_entry(
                 app_handle,
                 window,
                 task_id,
                 "error",
-                &format!("Translation failed: {}. Falling back to original SRT.", e),
+                &format!(
+                    "Failed to read translation memory: {:#}. Translating every cue.",
+                    e
+                ),
             )
             .await?;
+            Ok(HashMap::new())
+        }
+    }
+}
+
+/// Source and translated texts of the cues of successful chunks
+/// Chunks whose cue count changed are left out, since their cues can't be paired reliably
+fn translated_pairs(
+    sent: &SubtitleDocument,
+    translation: &ChunkedTranslation,
+) -> Vec<(String, String)> {
+    translation
+        .spans
+        .iter()
+        .filter(|span| !span.failed && span.source.len() == span.translated.len())
+        .flat_map(|span| {
+            sent.cues[span.source.clone()]
+                .iter()
+                .zip(&translation.document.cues[span.translated.clone()])
+        })
+        .map(|(original, translated)| (original.text.clone(), translated.text.clone()))
+        .collect()
+}
+
+/// Store the cues of successful chunks in the translation memory
+async fn remember_translations(
+    key: &MemoryKey,
+    sent: &SubtitleDocument,
+    translation: &ChunkedTranslation,
+    task_id: &str,
+    window: &Window,
+    app_handle: &AppHandle,
+) -> Result<()> {
+    let pairs = translated_pairs(sent, translation);
+    if pairs.is_empty() {
+        return Ok(());
+    }
 
-            // Copy original SRT to final output location
-            tokio::fs::copy(original_srt_path, &final_srt_path_str)
-                .await
-                .context("Failed to copy original SRT as fallback")?;
+    if let Err(e) = translation_memory::store(app_handle, key, pairs).await {
+        crate::logger::append_log_entry(
+            app_handle,
+            window,
+            task_id,
+            "error",
+            &format!("Failed to update translation memory: {:#}", e),
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
+/// Rebuild the full translated document from cached cues and the translated uncached ones
+/// Uncached cues are paired by position when the server kept the cue count, otherwise by
+/// start time; a cue with no translation keeps its original text
+fn merge_cached(
+    original: &SubtitleDocument,
+    cached: &HashMap<String, String>,
+    translated: SubtitleDocument,
+) -> SubtitleDocument {
+    if cached.is_empty() {
+        return translated;
+    }
+
+    let uncached_count = original
+        .cues
+        .iter()
+        .filter(|cue| !cached.contains_key(&cue.text))
+        .count();
+    let by_position = translated.len() == uncached_count;
+    let mut translated_cues = translated.cues.into_iter();
+    let mut remaining: Vec<Cue> = Vec::new();
+    if !by_position {
+        remaining = translated_cues.by_ref().collect();
+    }
 
+    let cues = original
+        .cues
+        .iter()
+        .map(|cue| match cached.get(&cue.text) {
+            Some(text) => Cue {
+                text: text.clone(),
+                ..cue.clone()
+            },
+            None if by_position => translated_cues.next().unwrap_or_else(|| cue.clone()),
+            None => remaining
+                .iter()
+                .find(|translated_cue| translated_cue.start_ms == cue.start_ms)
+                .cloned()
+                .unwrap_or_else(|| cue.clone()),
+        })
+        .collect();
+
+    SubtitleDocument::new(cues)
+}
+
+/// Glossary terms for `target_language` from the batch's glossary project, if one is set
+/// A missing glossary is logged and the translation continues without it
+async fn load_glossary_terms(
+    options: &TranslationOptions,
+    target_language: &str,
+    task_id: &str,
+    window: &Window,
+    app_handle: &AppHandle,
+) -> Result<Vec<GlossaryEntry>> {
+    let Some(project) = options.glossary_project.as_deref() else {
+        return Ok(Vec::new());
+    };
+
+    match glossary::load_glossary(app_handle, project).await {
+        Ok(Some(glossary)) => {
+            let terms = glossary.terms_for(target_language);
+            crate::logger::append_log_entry(
+                app_handle,
+                window,
+                task_id,
+                "translation",
+                &format!(
+                    "Using glossary '{}' ({} terms for {})",
+                    glossary.project,
+                    terms.len(),
+                    target_language
+                ),
+            )
+            .await?;
+            Ok(terms)
+        }
+        Ok(None) => {
             crate::logger::append_log_entry(
                 app_handle,
                 window,
                 task_id,
-                "metadata",
-                &format!("Original SRT saved to: {}", final_srt_path_str),
+                "error",
+                &format!("Glossary '{}' not found. Translating without it.", project),
             )
             .await?;
+            Ok(Vec::new())
+        }
+        Err(e) => {
+            crate::logger::append_log_entry(
+                app_handle,
+                window,
+                task_id,
+                "error",
+                &format!(
+                    "Failed to load glossary '{}': {:#}. Translating without it.",
+                    project, e
+                ),
+            )
+            .await?;
+            Ok(Vec::new())
+        }
+    }
+}
+
+/// Verify a translation against the glossary: log every cue whose original contains a
+/// term but whose translation lacks the required target term, and write them to a CSV
+/// QA report. Returns the report path if any cue was flagged.
+async fn check_glossary(
+    task_id: &str,
+    original: &SubtitleDocument,
+    translated: &SubtitleDocument,
+    terms: &[GlossaryEntry],
+    report_path: &Path,
+    window: &Window,
+    app_handle: &AppHandle,
+) -> Result<Option<String>> {
+    let issues = glossary::check(original, translated, terms);
+
+    if issues.is_empty() {
+        crate::logger::append_log_entry(
+            app_handle,
+            window,
+            task_id,
+            "translation",
+            "Glossary check passed: every glossary term was translated as required",
+        )
+        .await?;
+        return Ok(None);
+    }
+
+    for issue in &issues {
+        crate::logger::append_log_entry(
+            app_handle,
+            window,
+            task_id,
+            "translation",
+            &format!(
+                "Glossary: cue {} ({}) contains '{}' but its translation lacks '{}': {}",
+                issue.cue,
+                srt::format_timestamp(issue.start_ms),
+                issue.source,
+                issue.expected,
+                issue.translated_text.replace('\n', " / ")
+            ),
+        )
+        .await?;
+    }
+
+    tokio::fs::write(report_path, glossary::report_to_csv(&issues))
+        .await
+        .context("Failed to write glossary QA report")?;
+    let report_path_str = report_path
+        .to_str()
+        .context("Invalid glossary QA report path")?
+        .to_string();
+
+    crate::logger::append_log_entry(
+        app_handle,
+        window,
+        task_id,
+        "translation",
+        &format!(
+            "Glossary check flagged {} cue(s), QA report saved to {}",
+            issues.len(),
+            report_path_str
+        ),
+    )
+    .await?;
+
+    Ok(Some(report_path_str))
+}
+
+/// Outputs of the languages that were written and the errors of those that were not
+pub struct LanguageResults {
+    /// In the order of the target languages
+    pub outputs: Vec<TranslationOutput>,
+    pub failures: Vec<anyhow::Error>,
+}
+
+impl LanguageResults {
+    /// The failed languages and their errors, or None if every language was written
+    pub fn failure_summary(&self) -> Option<String> {
+        if self.failures.is_empty() {
+            return None;
         }
+        let errors: Vec<String> = self.failures.iter().map(|e| format!("{:#}", e)).collect();
+        Some(errors.join("; "))
     }
+}
 
-    // Emit translation complete event (whether translated or fallback)
+/// Translate one subtitle file into several languages, up to MAX_CONCURRENT_LANGUAGES at a time
+/// Each finished language is recorded in the task's job record (by its SRT file if one was
+/// written, as that is what gets muxed or burned in) so a resumed task skips it
+/// A language that fails does not stop the others; only a cancellation fails the whole call
+#[allow(clippy::too_many_arguments)]
+pub async fn translate_srt_languages(
+    server_url: &str,
+    task_id: &str,
+    original_srt_path: &str,
+    target_languages: &[String],
+    output_folder: &str,
+    original_file_path: &str,
+    output: &OutputOptions,
+    options: &TranslationOptions,
+    chunk_slots: &Arc<Semaphore>,
+    window: &Window,
+    app_handle: &AppHandle,
+    cancel_token: &CancellationToken,
+) -> Result<LanguageResults> {
+    let semaphore = Arc::new(Semaphore::new(MAX_CONCURRENT_LANGUAGES));
+    let mut join_set = tokio::task::JoinSet::new();
+
+    for (position, language) in target_languages.iter().enumerate() {
+        let semaphore = semaphore.clone();
+        let server_url = server_url.to_string();
+        let task_id = task_id.to_string();
+        let original_srt_path = original_srt_path.to_string();
+        let language = language.clone();
+        let output_folder = output_folder.to_string();
+        let original_file_path = original_file_path.to_string();
+        let output = output.clone();
+        let options = options.clone();
+        let chunk_slots = chunk_slots.clone();
+        let window = window.clone();
+        let app_handle = app_handle.clone();
+        let cancel_token = cancel_token.clone();
+
+        join_set.spawn(async move {
+            let _permit = semaphore.acquire_owned().await;
+            let result = translate_srt(
+                &server_url,
+                &task_id,
+                &original_srt_path,
+                &language,
+                &output_folder,
+                &original_file_path,
+                &output,
+                &options,
+                &chunk_slots,
+                &window,
+                &app_handle,
+                &cancel_token,
+            )
+            .await;
+            (position, language, result)
+        });
+    }
+
+    let mut results: Vec<Option<TranslationOutput>> =
+        target_languages.iter().map(|_| None).collect();
+    let mut failures = Vec::new();
+    let mut cancelled = None;
+
+    while let Some(joined) = join_set.join_next().await {
+        let (position, language, result) = joined.context("Translation task panicked")?;
+        match result {
+            Ok(translation_output) => {
+                let recorded_path =
+                    translation_output
+                        .path_for(SubtitleFormat::Srt)
+                        .or_else(|| {
+                            translation_output
+                                .files
+                                .first()
+                                .map(|(_, path)| path.as_str())
+                        });
+                if let Some(recorded_path) = recorded_path {
+                    let _ = job_store::update_job(app_handle, task_id, |job| {
+                        job.translated_srt_paths
+                            .insert(language.clone(), recorded_path.to_string());
+                        job.translation_statuses
+                            .insert(language.clone(), translation_output.status);
+                    })
+                    .await;
+                }
+                results[position] = Some(translation_output);
+            }
+            Err(e) if task_registry::is_cancelled(&e) => cancelled = Some(e),
+            Err(e) => failures.push(e.context(format!("Translation to {} failed", language))),
+        }
+    }
+
+    if let Some(e) = cancelled {
+        return Err(e);
+    }
+
+    Ok(LanguageResults {
+        outputs: results.into_iter().flatten().collect(),
+        failures,
+    })
+}
+
+/// Emit `translation:complete` with the outputs of every target language
+pub fn emit_translation_complete(
+    window: &Window,
+    task_id: &str,
+    output_paths: Vec<String>,
+    status: TranslationStatus,
+) -> Result<()> {
     window
         .emit(
             "translation:complete",
             TranslationCompletePayload {
                 task_id: task_id.to_string(),
-                translated_srt_path: final_srt_path_str.clone(),
+                translated_srt_path: output_paths.first().cloned().unwrap_or_default(),
+                status,
+                output_paths,
             },
         )
-        .context("Failed to emit translation:complete event")?;
+        .context("Failed to emit translation:complete event")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    Ok(final_srt_path_str)
+    fn texts(document: &SubtitleDocument) -> Vec<&str> {
+        document.cues.iter().map(|cue| cue.text.as_str()).collect()
+    }
+
+    fn chunked(cues: Vec<Cue>, spans: Vec<ChunkSpan>) -> ChunkedTranslation {
+        ChunkedTranslation {
+            document: SubtitleDocument::new(cues),
+            failed_chunks: spans.iter().filter(|span| span.failed).count(),
+            total_chunks: spans.len(),
+            spans,
+            error: None,
+        }
+    }
+
+    #[test]
+    fn merges_cached_cues_in_order() {
+        let original = SubtitleDocument::new(vec![
+            Cue::new(0, 1000, "One"),
+            Cue::new(1000, 2000, "Two"),
+            Cue::new(2000, 3000, "Three"),
+        ]);
+        let cached = HashMap::from([("Two".to_string(), "Deux".to_string())]);
+
+        let by_position =
+            SubtitleDocument::new(vec![Cue::new(0, 1000, "Un"), Cue::new(2000, 3000, "Trois")]);
+        let merged = merge_cached(&original, &cached, by_position);
+        assert_eq!(texts(&merged), vec!["Un", "Deux", "Trois"]);
+        assert_eq!(merged.cues[1].start_ms, 1000);
+
+        // A re-segmented translation is paired by start time; unmatched cues keep their text
+        let by_time = SubtitleDocument::new(vec![
+            Cue::new(2000, 2500, "Trois"),
+            Cue::new(2500, 3000, "et demi"),
+            Cue::new(3000, 3500, "Quatre"),
+        ]);
+        let merged = merge_cached(&original, &cached, by_time);
+        assert_eq!(texts(&merged), vec!["One", "Deux", "Trois"]);
+    }
+
+    #[test]
+    fn pairs_only_chunks_that_kept_their_cue_count() {
+        let sent = SubtitleDocument::new(vec![
+            Cue::new(0, 1000, "A"),
+            Cue::new(1000, 2000, "B"),
+            Cue::new(2000, 3000, "C"),
+            Cue::new(3000, 4000, "D"),
+            Cue::new(4000, 5000, "E"),
+            Cue::new(5000, 6000, "F"),
+        ]);
+
+        // The first chunk gained a cue and the second lost one, so the totals match
+        // but neither chunk can be paired; the third chunk failed and kept its text
+        let translation = chunked(
+            vec![
+                Cue::new(0, 500, "a1"),
+                Cue::new(500, 1000, "a2"),
+                Cue::new(1000, 2000, "b"),
+                Cue::new(2000, 4000, "cd"),
+                Cue::new(4000, 5000, "E"),
+                Cue::new(5000, 6000, "F"),
+            ],
+            vec![
+                ChunkSpan {
+                    source: 0..2,
+                    translated: 0..3,
+                    failed: false,
+                },
+                ChunkSpan {
+                    source: 2..4,
+                    translated: 3..4,
+                    failed: false,
+                },
+                ChunkSpan {
+                    source: 4..6,
+                    translated: 4..6,
+                    failed: true,
+                },
+            ],
+        );
+        assert!(translated_pairs(&sent, &translation).is_empty());
+
+        let translation = chunked(
+            vec![
+                Cue::new(0, 1000, "a"),
+                Cue::new(1000, 2000, "b"),
+                Cue::new(2000, 4000, "cd"),
+                Cue::new(4000, 5000, "e"),
+                Cue::new(5000, 6000, "f"),
+            ],
+            vec![
+                ChunkSpan {
+                    source: 0..2,
+                    translated: 0..2,
+                    failed: false,
+                },
+                ChunkSpan {
+                    source: 2..4,
+                    translated: 2..3,
+                    failed: false,
+                },
+                ChunkSpan {
+                    source: 4..6,
+                    translated: 3..5,
+                    failed: false,
+                },
+            ],
+        );
+        let pairs = translated_pairs(&sent, &translation);
+        let pairs: Vec<(&str, &str)> = pairs
+            .iter()
+            .map(|(source, target)| (source.as_str(), target.as_str()))
+            .collect();
+        assert_eq!(pairs, vec![("A", "a"), ("B", "b"), ("E", "e"), ("F", "f")]);
+    }
 }
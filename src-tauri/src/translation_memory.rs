@@ -0,0 +1,513 @@
+use anyhow::{Context, Result};
+use quick_xml::events::Event;
+use quick_xml::Reader;
+use rusqlite::{params, Connection, OptionalExtension};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Manager};
+
+/// Languages with no known source language are exported with this TMX language tag
+const UNDETERMINED_LANGUAGE: &str = "und";
+
+/// What a cached translation depends on besides the source text
+/// Languages, country and model are compared case-insensitively; empty means
+/// unknown/server default. `glossary` is the fingerprint of the glossary terms used.
+#[derive(Debug, Clone)]
+pub struct MemoryKey {
+    pub source_language: String,
+    pub target_language: String,
+    pub country: String,
+    pub model: String,
+    pub glossary: String,
+}
+
+impl MemoryKey {
+    pub fn new(
+        source_language: Option<&str>,
+        target_language: &str,
+        country: Option<&str>,
+        model: Option<&str>,
+        glossary: &str,
+    ) -> Self {
+        Self {
+            source_language: source_language.unwrap_or_default().trim().to_string(),
+            target_language: target_language.trim().to_string(),
+            country: country.unwrap_or_default().trim().to_string(),
+            model: model.unwrap_or_default().trim().to_string(),
+            glossary: glossary.to_string(),
+        }
+    }
+}
+
+/// One translation unit of the memory
+#[derive(Debug, Clone, PartialEq)]
+pub struct MemoryEntry {
+    pub source_text: String,
+    pub source_language: String,
+    pub target_language: String,
+    pub country: String,
+    pub model: String,
+    pub glossary: String,
+    pub target_text: String,
+}
+
+/// Get the translation memory database path (sibling of the logs directory)
+fn get_memory_path(app_handle: &AppHandle) -> Result<PathBuf> {
+    let app_data_dir = app_handle
+        .path()
+        .app_data_dir()
+        .context("Failed to get app data directory")?;
+
+    std::fs::create_dir_all(&app_data_dir).context("Failed to create app data directory")?;
+
+    Ok(app_data_dir.join("translation_memory.sqlite3"))
+}
+
+const CREATE_TABLE: &str = "CREATE TABLE IF NOT EXISTS translation_memory (
+    source_text TEXT NOT NULL,
+    source_language TEXT NOT NULL COLLATE NOCASE,
+    target_language TEXT NOT NULL COLLATE NOCASE,
+    country TEXT NOT NULL COLLATE NOCASE,
+    model TEXT NOT NULL COLLATE NOCASE,
+    glossary TEXT NOT NULL,
+    target_text TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    PRIMARY KEY (source_text, source_language, target_language, country, model, glossary)
+);";
+
+fn open_memory(path: &Path) -> Result<Connection> {
+    let connection = Connection::open(path).context("Failed to open translation memory")?;
+
+    // Memories from before country and glossary were part of the key keep their
+    // entries, which then only match translations without either
+    let has_table = connection
+        .prepare(
+            "SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = 'translation_memory'",
+        )?
+        .exists([])?;
+    let has_country = connection
+        .prepare("SELECT 1 FROM pragma_table_info('translation_memory') WHERE name = 'country'")?
+        .exists([])?;
+    if has_table && !has_country {
+        connection
+            .execute_batch(&format!(
+                "BEGIN;
+                 ALTER TABLE translation_memory RENAME TO translation_memory_old;
+                 {}
+                 INSERT INTO translation_memory
+                    (source_text, source_language, target_language, country, model, glossary,
+                     target_text, updated_at)
+                 SELECT source_text, source_language, target_language, '', model, '',
+                        target_text, updated_at
+                 FROM translation_memory_old;
+                 DROP TABLE translation_memory_old;
+                 COMMIT;",
+                CREATE_TABLE
+            ))
+            .context("Failed to upgrade translation memory")?;
+    }
+
+    connection
+        .execute_batch(CREATE_TABLE)
+        .context("Failed to create translation memory table")?;
+    Ok(connection)
+}
+
+/// Run a blocking database operation on the memory without stalling the async runtime
+async fn with_memory<T, F>(app_handle: &AppHandle, operation: F) -> Result<T>
+where
+    F: FnOnce(&mut Connection) -> Result<T> + Send + 'static,
+    T: Send + 'static,
+{
+    let path = get_memory_path(app_handle)?;
+    tokio::task::spawn_blocking(move || {
+        let mut connection = open_memory(&path)?;
+        operation(&mut connection)
+    })
+    .await
+    .context("Translation memory task panicked")?
+}
+
+/// Cached translations of `texts`, keyed by source text
+pub async fn lookup(
+    app_handle: &AppHandle,
+    key: &MemoryKey,
+    texts: Vec<String>,
+) -> Result<HashMap<String, String>> {
+    let key = key.clone();
+    with_memory(app_handle, move |connection| {
+        let mut statement = connection.prepare(
+            "SELECT target_text FROM translation_memory
+             WHERE source_text = ?1 AND source_language = ?2 AND target_language = ?3
+               AND country = ?4 AND model = ?5 AND glossary = ?6",
+        )?;
+
+        let mut found = HashMap::new();
+        for text in texts {
+            let target_text: Option<String> = statement
+                .query_row(
+                    params![
+                        text,
+                        key.source_language,
+                        key.target_language,
+                        key.country,
+                        key.model,
+                        key.glossary
+                    ],
+                    |row| row.get(0),
+                )
+                .optional()
+                .context("Failed to query translation memory")?;
+            if let Some(target_text) = target_text {
+                found.insert(text, target_text);
+            }
+        }
+        Ok(found)
+    })
+    .await
+}
+
+/// Add or replace translations under `key`
+pub async fn store(
+    app_handle: &AppHandle,
+    key: &MemoryKey,
+    pairs: Vec<(String, String)>,
+) -> Result<()> {
+    let entries = pairs
+        .into_iter()
+        .map(|(source_text, target_text)| MemoryEntry {
+            source_text,
+            source_language: key.source_language.clone(),
+            target_language: key.target_language.clone(),
+            country: key.country.clone(),
+            model: key.model.clone(),
+            glossary: key.glossary.clone(),
+            target_text,
+        })
+        .collect();
+    store_entries(app_handle, entries).await.map(|_| ())
+}
+
+/// Add or replace entries in a single transaction; returns how many were written
+async fn store_entries(app_handle: &AppHandle, entries: Vec<MemoryEntry>) -> Result<usize> {
+    with_memory(app_handle, move |connection| {
+        let updated_at = chrono::Utc::now().to_rfc3339();
+        let transaction = connection.transaction()?;
+        {
+            let mut statement = transaction.prepare(
+                "INSERT INTO translation_memory
+                    (source_text, source_language, target_language, country, model, glossary,
+                     target_text, updated_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+                 ON CONFLICT (source_text, source_language, target_language, country, model, glossary)
+                 DO UPDATE SET target_text = excluded.target_text, updated_at = excluded.updated_at",
+            )?;
+            for entry in &entries {
+                statement
+                    .execute(params![
+                        entry.source_text,
+                        entry.source_language,
+                        entry.target_language,
+                        entry.country,
+                        entry.model,
+                        entry.glossary,
+                        entry.target_text,
+                        updated_at
+                    ])
+                    .context("Failed to write translation memory entry")?;
+            }
+        }
+        transaction
+            .commit()
+            .context("Failed to save translation memory")?;
+        Ok(entries.len())
+    })
+    .await
+}
+
+/// Write the whole memory to a TMX 1.4 file; returns the number of translation units
+pub async fn export_tmx(app_handle: &AppHandle, file_path: &str) -> Result<usize> {
+    let entries = with_memory(app_handle, |connection| {
+        let mut statement = connection.prepare(
+            "SELECT source_text, source_language, target_language, country, model, glossary,
+                    target_text
+             FROM translation_memory
+             ORDER BY source_language, target_language, country, model, glossary, source_text",
+        )?;
+        let entries = statement
+            .query_map([], |row| {
+                Ok(MemoryEntry {
+                    source_text: row.get(0)?,
+                    source_language: row.get(1)?,
+                    target_language: row.get(2)?,
+                    country: row.get(3)?,
+                    model: row.get(4)?,
+                    glossary: row.get(5)?,
+                    target_text: row.get(6)?,
+                })
+            })?
+            .collect::<rusqlite::Result<Vec<_>>>()
+            .context("Failed to read translation memory")?;
+        Ok(entries)
+    })
+    .await?;
+
+    tokio::fs::write(file_path, to_tmx(&entries))
+        .await
+        .with_context(|| format!("Failed to write TMX file: {}", file_path))?;
+
+    Ok(entries.len())
+}
+
+/// Merge a TMX file into the memory; returns the number of translations imported
+pub async fn import_tmx(app_handle: &AppHandle, file_path: &str) -> Result<usize> {
+    let content = tokio::fs::read_to_string(file_path)
+        .await
+        .with_context(|| format!("Failed to read TMX file: {}", file_path))?;
+    let entries = parse_tmx(&content)?;
+
+    store_entries(app_handle, entries).await
+}
+
+fn escape_xml(text: &str) -> String {
+    quick_xml::escape::escape(text).into_owned()
+}
+
+/// Render entries as TMX 1.4; country, model and glossary fingerprint are kept in
+/// `x-country`, `x-model` and `x-glossary` properties
+pub fn to_tmx(entries: &[MemoryEntry]) -> String {
+    let mut output = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    output.push_str("<tmx version=\"1.4\">\n");
+    output.push_str(&format!(
+        "  <header creationtool=\"translation-app\" creationtoolversion=\"{}\" segtype=\"block\" o-tmf=\"translation-app\" adminlang=\"en\" srclang=\"*all*\" datatype=\"plaintext\"/>\n",
+        env!("CARGO_PKG_VERSION")
+    ));
+    output.push_str("  <body>\n");
+
+    for entry in entries {
+        let source_language = if entry.source_language.is_empty() {
+            UNDETERMINED_LANGUAGE
+        } else {
+            &entry.source_language
+        };
+
+        output.push_str(&format!(
+            "    <tu srclang=\"{}\">\n",
+            escape_xml(source_language)
+        ));
+        for (property, value) in [
+            ("x-country", &entry.country),
+            ("x-model", &entry.model),
+            ("x-glossary", &entry.glossary),
+        ] {
+            if !value.is_empty() {
+                output.push_str(&format!(
+                    "      <prop type=\"{}\">{}</prop>\n",
+                    property,
+                    escape_xml(value)
+                ));
+            }
+        }
+        for (language, text) in [
+            (source_language, &entry.source_text),
+            (entry.target_language.as_str(), &entry.target_text),
+        ] {
+            output.push_str(&format!(
+                "      <tuv xml:lang=\"{}\"><seg>{}</seg></tuv>\n",
+                escape_xml(language),
+                escape_xml(text)
+            ));
+        }
+        output.push_str("    </tu>\n");
+    }
+
+    output.push_str("  </body>\n</tmx>\n");
+    output
+}
+
+/// Parse the translation units of a TMX file
+/// Each variant other than the source (the unit's `srclang`, or its first variant)
+/// becomes one entry. Inline markup inside segments is dropped, keeping its text.
+pub fn parse_tmx(content: &str) -> Result<Vec<MemoryEntry>> {
+    let mut reader = Reader::from_str(content);
+    let mut entries = Vec::new();
+
+    let mut unit_source_language: Option<String> = None;
+    // Values of the unit's `x-country`, `x-model` and `x-glossary` properties
+    let mut unit_properties: HashMap<String, String> = HashMap::new();
+    let mut variants: Vec<(String, String)> = Vec::new();
+    let mut variant_language: Option<String> = None;
+    let mut property: Option<String> = None;
+    let mut segment: Option<String> = None;
+
+    loop {
+        match reader.read_event().context("Invalid TMX file")? {
+            Event::Start(element) => match element.local_name().as_ref() {
+                b"tu" => {
+                    unit_source_language = element
+                        .try_get_attribute("srclang")?
+                        .map(|attribute| attribute.unescape_value().map(|v| v.into_owned()))
+                        .transpose()?;
+                    unit_properties.clear();
+                    variants.clear();
+                }
+                b"tuv" => {
+                    let language = element
+                        .try_get_attribute("xml:lang")?
+                        .or(element.try_get_attribute("lang")?)
+                        .map(|attribute| attribute.unescape_value().map(|v| v.into_owned()))
+                        .transpose()?;
+                    variant_language = language;
+                }
+                b"prop" => {
+                    property = element
+                        .try_get_attribute("type")?
+                        .map(|attribute| attribute.unescape_value().map(|v| v.into_owned()))
+                        .transpose()?;
+                }
+                b"seg" => segment = Some(String::new()),
+                _ => {}
+            },
+            Event::Text(text) => {
+                let text = text.xml_content()?;
+                if let Some(segment) = segment.as_mut() {
+                    segment.push_str(&text);
+                } else if let Some(property) = &property {
+                    unit_properties
+                        .entry(property.clone())
+                        .or_default()
+                        .push_str(&text);
+                }
+            }
+            Event::CData(data) => {
+                if let Some(segment) = segment.as_mut() {
+                    segment.push_str(&data.decode()?);
+                }
+            }
+            Event::GeneralRef(reference) => {
+                let resolved = match reference.resolve_char_ref()? {
+                    Some(c) => c.to_string(),
+                    None => {
+                        let name = reference.decode()?;
+                        quick_xml::escape::resolve_xml_entity(&name)
+                            .with_context(|| format!("Unknown entity in TMX file: &{};", name))?
+                            .to_string()
+                    }
+                };
+                if let Some(segment) = segment.as_mut() {
+                    segment.push_str(&resolved);
+                } else if let Some(property) = &property {
+                    unit_properties
+                        .entry(property.clone())
+                        .or_default()
+                        .push_str(&resolved);
+                }
+            }
+            Event::End(element) => match element.local_name().as_ref() {
+                b"seg" => {
+                    if let (Some(language), Some(text)) = (variant_language.clone(), segment.take())
+                    {
+                        variants.push((language, text));
+                    }
+                }
+                b"tuv" => variant_language = None,
+                b"prop" => property = None,
+                b"tu" => {
+                    let source_index = unit_source_language
+                        .as_deref()
+                        .and_then(|source| {
+                            variants
+                                .iter()
+                                .position(|(language, _)| language.eq_ignore_ascii_case(source))
+                        })
+                        .unwrap_or(0);
+                    let Some((source_language, source_text)) = variants.get(source_index).cloned()
+                    else {
+                        continue;
+                    };
+                    let source_language = if source_language == UNDETERMINED_LANGUAGE {
+                        String::new()
+                    } else {
+                        source_language
+                    };
+
+                    let unit_property = |name: &str| {
+                        unit_properties
+                            .get(name)
+                            .map(|value| value.trim().to_string())
+                            .unwrap_or_default()
+                    };
+
+                    for (index, (language, text)) in variants.iter().enumerate() {
+                        if index == source_index || text.trim().is_empty() {
+                            continue;
+                        }
+                        entries.push(MemoryEntry {
+                            source_text: source_text.clone(),
+                            source_language: source_language.clone(),
+                            target_language: language.clone(),
+                            country: unit_property("x-country"),
+                            model: unit_property("x-model"),
+                            glossary: unit_property("x-glossary"),
+                            target_text: text.clone(),
+                        });
+                    }
+                }
+                _ => {}
+            },
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tmx_round_trip() {
+        let entries = vec![
+            MemoryEntry {
+                source_text: "Tom & Jerry\n<run>".to_string(),
+                source_language: "English".to_string(),
+                target_language: "Japanese".to_string(),
+                country: String::new(),
+                model: "gpt-4o".to_string(),
+                glossary: "5f3a9c0e12b4d678".to_string(),
+                target_text: "トムとジェリー\n走れ".to_string(),
+            },
+            MemoryEntry {
+                source_text: "Hello".to_string(),
+                source_language: String::new(),
+                target_language: "German".to_string(),
+                country: "AT".to_string(),
+                model: String::new(),
+                glossary: String::new(),
+                target_text: "Hallo".to_string(),
+            },
+        ];
+
+        assert_eq!(parse_tmx(&to_tmx(&entries)).unwrap(), entries);
+    }
+
+    #[test]
+    fn parses_multilingual_units_and_char_refs() {
+        let tmx = r#"<tmx version="1.4"><header srclang="en"/><body>
+            <tu srclang="en">
+              <tuv xml:lang="fr"><seg>Bonjour &#233;t&#xE9;</seg></tuv>
+              <tuv xml:lang="en"><seg>Hello <bpt i="1">&lt;b&gt;</bpt>summer</seg></tuv>
+              <tuv xml:lang="de"><seg>Hallo Sommer</seg></tuv>
+            </tu>
+        </body></tmx>"#;
+
+        let entries = parse_tmx(tmx).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].source_text, "Hello <b>summer");
+        assert_eq!(entries[0].target_language, "fr");
+        assert_eq!(entries[0].target_text, "Bonjour été");
+        assert_eq!(entries[1].target_text, "Hallo Sommer");
+    }
+}
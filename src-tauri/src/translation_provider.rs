@@ -0,0 +1,189 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+use std::time::Duration;
+
+use crate::glossary::GlossaryEntry;
+use crate::libretranslate::LibreTranslateSettings;
+use crate::openai_translation::OpenAiSettings;
+use crate::subtitle::{srt, Cue, SubtitleDocument};
+
+/// Limit for one chunk request, so a server that never answers can't hold a chunk slot
+pub const REQUEST_TIMEOUT: Duration = Duration::from_secs(180);
+
+/// Per-batch hints passed to the translation provider; unset fields use its defaults
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TranslationOptions {
+    /// Service that translates the cues; the translation server by default
+    #[serde(rename = "translationProvider", default)]
+    pub provider: TranslationProviderConfig,
+    /// Language of the original subtitles; filled from the transcription backend if unset
+    #[serde(
+        rename = "sourceLanguage",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub source_language: Option<String>,
+    /// Regional variant of the target language, e.g. "BR" for Brazilian Portuguese
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
+    /// Translation model to use on the translation server
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    /// Project whose glossary is sent with each chunk and checked afterwards
+    #[serde(
+        rename = "glossaryProject",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub glossary_project: Option<String>,
+}
+
+/// Which service translates the subtitles of a batch
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum TranslationProviderConfig {
+    /// The translation server at the batch's server URL, translating whole SRT chunks
+    #[default]
+    Server,
+    /// An OpenAI-compatible chat completions endpoint, e.g. a local llama.cpp or Ollama server
+    OpenAiCompatible(OpenAiSettings),
+    /// A LibreTranslate instance
+    LibreTranslate(LibreTranslateSettings),
+}
+
+impl TranslationProviderConfig {
+    /// Where the cues are sent, for the task log
+    pub fn describe(&self, server_url: &str) -> String {
+        match self {
+            TranslationProviderConfig::Server => format!("translation server {}", server_url),
+            TranslationProviderConfig::OpenAiCompatible(settings) => {
+                format!("{} at {}", settings.model, settings.base_url)
+            }
+            TranslationProviderConfig::LibreTranslate(settings) => {
+                format!("LibreTranslate at {}", settings.url)
+            }
+        }
+    }
+
+    /// Model recorded with translation memory entries, so providers don't share translations
+    pub fn memory_model(&self, options: &TranslationOptions) -> Option<String> {
+        match self {
+            TranslationProviderConfig::Server => options.model.clone(),
+            TranslationProviderConfig::OpenAiCompatible(settings) => Some(settings.model.clone()),
+            TranslationProviderConfig::LibreTranslate(_) => Some("libretranslate".to_string()),
+        }
+    }
+}
+
+/// A service that translates a run of cues into the target language.
+/// Implementations are shared by the concurrent chunk requests of a document.
+pub trait TranslationProvider: Send + Sync + 'static {
+    /// Translate `cues` (a single attempt); `glossary` holds the terms that appear in them.
+    /// The result may differ in length only if the provider re-segments the subtitles.
+    fn translate_cues(
+        &self,
+        cues: &[Cue],
+        target_language: &str,
+        options: &TranslationOptions,
+        glossary: &[GlossaryEntry],
+    ) -> impl Future<Output = Result<Vec<Cue>>> + Send;
+}
+
+#[derive(Debug, Serialize)]
+struct TranslationRequest {
+    srt_content: String,
+    target_language: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source_language: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    country: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    model: Option<String>,
+    /// Glossary terms that appear in this chunk
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    glossary: Vec<GlossaryEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TranslationResponse {
+    translated_srt: String,
+}
+
+/// The translation server's `/translate` endpoint, which takes and returns whole SRTs
+pub struct ServerProvider {
+    server_url: String,
+}
+
+impl ServerProvider {
+    pub fn new(server_url: &str) -> Self {
+        Self {
+            server_url: server_url.to_string(),
+        }
+    }
+}
+
+impl TranslationProvider for ServerProvider {
+    async fn translate_cues(
+        &self,
+        cues: &[Cue],
+        target_language: &str,
+        options: &TranslationOptions,
+        glossary: &[GlossaryEntry],
+    ) -> Result<Vec<Cue>> {
+        let client = reqwest::Client::new();
+        let request_body = TranslationRequest {
+            srt_content: srt::to_string(&SubtitleDocument::new(cues.to_vec())),
+            target_language: target_language.to_string(),
+            source_language: options.source_language.clone(),
+            country: options.country.clone(),
+            model: options.model.clone(),
+            glossary: glossary.to_vec(),
+        };
+
+        let response = client
+            .post(format!("{}/translate", self.server_url))
+            .timeout(REQUEST_TIMEOUT)
+            .header("Content-Type", "application/json")
+            .json(&request_body)
+            .send()
+            .await
+            .context("Network error during translation request")?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_default();
+            anyhow::bail!("[HTTP {}] Translation failed: {}", status, error_text);
+        }
+
+        let translation_response: TranslationResponse = response
+            .json()
+            .await
+            .context("Failed to parse translation response")?;
+
+        // A response that isn't valid SRT counts as a failed attempt
+        let document = srt::parse(&translation_response.translated_srt)
+            .context("Translation server returned invalid SRT")?;
+        Ok(document.cues)
+    }
+}
+
+/// Copy of `cues` with each text replaced by its translation, keeping the timings
+pub fn with_texts(cues: &[Cue], texts: Vec<String>) -> Result<Vec<Cue>> {
+    if texts.len() != cues.len() {
+        anyhow::bail!(
+            "Provider returned {} translations for {} cues",
+            texts.len(),
+            cues.len()
+        );
+    }
+
+    Ok(cues
+        .iter()
+        .zip(texts)
+        .map(|(cue, text)| Cue {
+            text: text.trim().to_string(),
+            ..cue.clone()
+        })
+        .collect())
+}
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+/// How much of a language's output is actually translated
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TranslationStatus {
+    /// Every chunk translated
+    Translated,
+    /// Some chunks failed and kept their original text (`<stem>_<lang>_partial.srt`)
+    Partial,
+    /// Nothing translated, the original text was written (`<stem>_<lang>_untranslated.srt`)
+    Fallback,
+}
+
+impl TranslationStatus {
+    /// Overall status of several languages
+    pub fn combine(statuses: impl IntoIterator<Item = TranslationStatus>) -> TranslationStatus {
+        let statuses: Vec<TranslationStatus> = statuses.into_iter().collect();
+        if statuses
+            .iter()
+            .all(|status| *status == TranslationStatus::Translated)
+        {
+            TranslationStatus::Translated
+        } else if statuses
+            .iter()
+            .all(|status| *status == TranslationStatus::Fallback)
+        {
+            TranslationStatus::Fallback
+        } else {
+            TranslationStatus::Partial
+        }
+    }
+}
@@ -0,0 +1,373 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager, Window};
+use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio::process::Command;
+use tokio_util::sync::CancellationToken;
+
+use crate::ffmpeg::TaskProgressPayload;
+use crate::logger;
+use crate::task_registry::TaskCancelled;
+use crate::transcription::{JobStatus, TranscriptionProvider};
+
+#[cfg(target_os = "windows")]
+#[allow(unused_imports)]
+use std::os::windows::process::CommandExt;
+
+#[cfg(target_os = "windows")]
+const CREATE_NO_WINDOW: u32 = 0x08000000;
+
+/// Lines of whisper.cpp output kept for the error message of a failed run
+const ERROR_TAIL_LINES: usize = 10;
+
+/// User-supplied whisper.cpp installation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WhisperCppSettings {
+    /// Path to the `whisper-cli` (or older `main`) executable
+    #[serde(rename = "binaryPath")]
+    pub binary_path: String,
+    /// Path to a ggml model file, e.g. `ggml-large-v3.bin`
+    #[serde(rename = "modelPath")]
+    pub model_path: String,
+    /// CPU threads; whisper.cpp's default if unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub threads: Option<u32>,
+    /// Spoken language, e.g. "ja"; detected automatically if unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+}
+
+impl WhisperCppSettings {
+    /// Check that the binary and the model exist
+    pub async fn validate(&self) -> Result<()> {
+        for (label, path) in [
+            ("whisper.cpp binary", &self.binary_path),
+            ("whisper.cpp model", &self.model_path),
+        ] {
+            let is_file = tokio::fs::metadata(path)
+                .await
+                .map(|metadata| metadata.is_file())
+                .unwrap_or(false);
+            if !is_file {
+                anyhow::bail!("{} not found: {}", label, path);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Finished local run, kept until its SRT is fetched
+struct FinishedRun {
+    srt_path: PathBuf,
+    language_code: Option<String>,
+}
+
+/// Transcribes on this machine's CPU with a whisper.cpp binary
+pub struct WhisperCppProvider {
+    settings: WhisperCppSettings,
+    /// Runs are finished by the time `submit` returns; `poll` and `fetch_result` read them here
+    finished_runs: Mutex<HashMap<String, FinishedRun>>,
+}
+
+impl WhisperCppProvider {
+    pub fn new(settings: WhisperCppSettings) -> Self {
+        Self {
+            settings,
+            finished_runs: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl Drop for WhisperCppProvider {
+    /// Delete the output of runs whose SRT was never fetched, e.g. after a cancellation
+    fn drop(&mut self) {
+        let runs = self
+            .finished_runs
+            .get_mut()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        for run in runs.values() {
+            let _ = std::fs::remove_file(&run.srt_path);
+        }
+    }
+}
+
+/// whisper.cpp appends `.srt` to the `--output-file` base name
+fn srt_output_path(output_base: &Path) -> PathBuf {
+    let mut path = output_base.as_os_str().to_owned();
+    path.push(".srt");
+    PathBuf::from(path)
+}
+
+/// Percentage from a `whisper_print_progress_callback: progress =  42%` line
+fn parse_progress(line: &str) -> Option<f64> {
+    let (_, rest) = line.split_once("progress =")?;
+    rest.trim().trim_end_matches('%').trim().parse().ok()
+}
+
+/// Language code from an `auto-detected language: en (p = 0.97)` line
+fn parse_detected_language(line: &str) -> Option<String> {
+    let (_, rest) = line.split_once("auto-detected language:")?;
+    rest.split_whitespace().next().map(str::to_string)
+}
+
+/// Run whisper.cpp on `audio_path`, writing `<output_base>.srt`
+/// Progress lines become `task:progress` events, everything else goes to the task log.
+/// Returns the detected language when whisper.cpp reports one.
+async fn run_whisper(
+    settings: &WhisperCppSettings,
+    audio_path: &str,
+    output_base: &Path,
+    task_id: &str,
+    window: &Window,
+    app_handle: &AppHandle,
+    cancel_token: &CancellationToken,
+) -> Result<Option<String>> {
+    let mut cmd = Command::new(&settings.binary_path);
+    cmd.arg("-m")
+        .arg(&settings.model_path)
+        .arg("-f")
+        .arg(audio_path)
+        .arg("-l")
+        .arg(settings.language.as_deref().unwrap_or("auto"))
+        .arg("--no-gpu")
+        .arg("--print-progress")
+        .arg("--output-srt")
+        .arg("--output-file")
+        .arg(output_base);
+    if let Some(threads) = settings.threads {
+        cmd.arg("--threads").arg(threads.to_string());
+    }
+    cmd.stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true);
+
+    // On Windows, prevent console window from appearing
+    #[cfg(target_os = "windows")]
+    cmd.creation_flags(CREATE_NO_WINDOW);
+
+    let mut child = cmd
+        .spawn()
+        .with_context(|| format!("Failed to start whisper.cpp: {}", settings.binary_path))?;
+
+    let stderr_handle = child.stderr.take().map(|stderr| {
+        let mut lines = BufReader::new(stderr).lines();
+        let window_clone = window.clone();
+        let app_handle_clone = app_handle.clone();
+        let task_id_clone = task_id.to_string();
+
+        tokio::spawn(async move {
+            let mut language_code = None;
+            let mut tail: Vec<String> = Vec::new();
+
+            while let Ok(Some(line)) = lines.next_line().await {
+                if let Some(percent) = parse_progress(&line) {
+                    let _ = window_clone.emit(
+                        "task:progress",
+                        TaskProgressPayload {
+                            task_id: task_id_clone.clone(),
+                            stage: "transcribing".to_string(),
+                            percent: percent.clamp(0.0, 100.0),
+                        },
+                    );
+                    continue;
+                }
+                if let Some(language) = parse_detected_language(&line) {
+                    language_code = Some(language);
+                }
+
+                let _ = logger::append_log_entry(
+                    &app_handle_clone,
+                    &window_clone,
+                    &task_id_clone,
+                    "transcription",
+                    &line,
+                )
+                .await;
+
+                tail.push(line);
+                if tail.len() > ERROR_TAIL_LINES {
+                    tail.remove(0);
+                }
+            }
+
+            (language_code, tail)
+        })
+    });
+
+    // Wait for the process to complete, killing it if the task is cancelled
+    let status = tokio::select! {
+        status = child.wait() => status.context("Failed to wait for whisper.cpp")?,
+        _ = cancel_token.cancelled() => {
+            let _ = child.kill().await;
+            if let Some(handle) = stderr_handle {
+                handle.abort();
+            }
+            let _ = tokio::fs::remove_file(srt_output_path(output_base)).await;
+
+            let _ = logger::append_log_entry(
+                app_handle,
+                window,
+                task_id,
+                "metadata",
+                "whisper.cpp transcription cancelled, process killed",
+            )
+            .await;
+
+            return Err(TaskCancelled.into());
+        }
+    };
+
+    let (language_code, tail) = match stderr_handle {
+        Some(handle) => handle.await.unwrap_or_default(),
+        None => (None, Vec::new()),
+    };
+
+    if !status.success() {
+        anyhow::bail!(
+            "whisper.cpp failed with status: {}\n{}",
+            status,
+            tail.join("\n")
+        );
+    }
+
+    Ok(language_code)
+}
+
+impl TranscriptionProvider for WhisperCppProvider {
+    fn name(&self) -> &str {
+        "whisper.cpp (local)"
+    }
+
+    fn is_resumable(&self) -> bool {
+        false
+    }
+
+    fn poll_interval(&self) -> Duration {
+        Duration::ZERO
+    }
+
+    /// Runs the whole transcription; the returned job is already finished
+    async fn submit(
+        &self,
+        audio_path: &str,
+        task_id: &str,
+        window: &Window,
+        app_handle: &AppHandle,
+        cancel_token: &CancellationToken,
+    ) -> Result<String> {
+        let temp_dir = app_handle
+            .path()
+            .temp_dir()
+            .context("Failed to get temp directory")?
+            .join("translation-app-whisper");
+        tokio::fs::create_dir_all(&temp_dir)
+            .await
+            .context("Failed to create whisper.cpp temp directory")?;
+        let output_base = temp_dir.join(task_id);
+
+        logger::append_log_entry(
+            app_handle,
+            window,
+            task_id,
+            "transcription",
+            &format!(
+                "Transcribing locally with whisper.cpp (model: {}, language: {})",
+                self.settings.model_path,
+                self.settings.language.as_deref().unwrap_or("auto")
+            ),
+        )
+        .await?;
+
+        let language_code = run_whisper(
+            &self.settings,
+            audio_path,
+            &output_base,
+            task_id,
+            window,
+            app_handle,
+            cancel_token,
+        )
+        .await?;
+
+        let job_id = format!("whisper-{}", task_id);
+        self.finished_runs.lock().unwrap().insert(
+            job_id.clone(),
+            FinishedRun {
+                srt_path: srt_output_path(&output_base),
+                language_code,
+            },
+        );
+
+        Ok(job_id)
+    }
+
+    async fn poll(
+        &self,
+        job_id: &str,
+        _task_id: &str,
+        _window: &Window,
+        _app_handle: &AppHandle,
+        _cancel_token: &CancellationToken,
+    ) -> Result<JobStatus> {
+        let runs = self.finished_runs.lock().unwrap();
+        Ok(match runs.get(job_id) {
+            Some(run) => JobStatus::Completed {
+                language_code: run.language_code.clone(),
+            },
+            None => JobStatus::Failed {
+                message: "whisper.cpp run not found".to_string(),
+            },
+        })
+    }
+
+    async fn fetch_result(
+        &self,
+        job_id: &str,
+        _task_id: &str,
+        _window: &Window,
+        _app_handle: &AppHandle,
+        _cancel_token: &CancellationToken,
+    ) -> Result<String> {
+        let run = self
+            .finished_runs
+            .lock()
+            .unwrap()
+            .remove(job_id)
+            .context("whisper.cpp run not found")?;
+
+        let srt_content = tokio::fs::read_to_string(&run.srt_path)
+            .await
+            .context("Failed to read whisper.cpp SRT output")?;
+        let _ = tokio::fs::remove_file(&run.srt_path).await;
+
+        Ok(srt_content)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_progress_and_language_lines() {
+        assert_eq!(
+            parse_progress("whisper_print_progress_callback: progress =  45%"),
+            Some(45.0)
+        );
+        assert_eq!(
+            parse_progress("whisper_init_from_file: loading model"),
+            None
+        );
+        assert_eq!(
+            parse_detected_language(
+                "whisper_full_with_state: auto-detected language: ja (p = 0.98)"
+            ),
+            Some("ja".to_string())
+        );
+    }
+}
@@ -101,7 +101,7 @@ export function SrtFileSelector() {
             Drag and drop SRT files or click to browse
           </p>
           <p className="text-xs text-muted-foreground">
-            Supports: .srt subtitle files
+            Supports: .srt, .ass and .ssa subtitle files
           </p>
         </div>
         <Button onClick={handleSelectFiles} size="lg">
@@ -1,4 +1,5 @@
 import {
+  AlertTriangle,
   CheckCircle2,
   Eye,
   FileVideo,
@@ -95,6 +96,17 @@ export const TaskItem = memo(function TaskItem({ task }: TaskItemProps) {
     return `${duration}s`
   }
 
+  const getMediaDuration = () => {
+    if (task.mediaDuration === undefined) return null
+    const totalSeconds = Math.round(task.mediaDuration)
+    const hours = Math.floor(totalSeconds / 3600)
+    const minutes = Math.floor((totalSeconds % 3600) / 60)
+    const seconds = String(totalSeconds % 60).padStart(2, '0')
+    return hours > 0
+      ? `${hours}:${String(minutes).padStart(2, '0')}:${seconds}`
+      : `${minutes}:${seconds}`
+  }
+
   const handleRemove = useCallback(() => {
     if (
       task.status !== 'processing' &&
@@ -124,11 +136,31 @@ export const TaskItem = memo(function TaskItem({ task }: TaskItemProps) {
                   <span className="mr-1">{getStatusIcon()}</span>
                   {getStatusText()}
                 </Badge>
-                {task.targetLanguage && (
+                {task.targetLanguage && !task.languageStatus && (
                   <Badge variant="outline">
                     Target: {task.targetLanguage.toUpperCase()}
                   </Badge>
                 )}
+                {task.languageStatus &&
+                  Object.entries(task.languageStatus).map(
+                    ([language, status]) => (
+                      <Badge key={language} variant="outline">
+                        <span className="mr-1">
+                          {status === 'translated' ? (
+                            <CheckCircle2 className="h-3 w-3" />
+                          ) : status === 'partial' || status === 'fallback' ? (
+                            <AlertTriangle className="h-3 w-3 text-yellow-500" />
+                          ) : (
+                            <Loader2 className="h-3 w-3 animate-spin" />
+                          )}
+                        </span>
+                        {language.toUpperCase()}
+                      </Badge>
+                    ),
+                  )}
+                {getMediaDuration() && (
+                  <Badge variant="outline">Length: {getMediaDuration()}</Badge>
+                )}
                 {getDuration() && (
                   <span className="text-xs text-muted-foreground">
                     {getDuration()}
@@ -138,8 +170,26 @@ export const TaskItem = memo(function TaskItem({ task }: TaskItemProps) {
             </div>
           </div>
 
+          {task.progress &&
+            (task.status === 'processing' ||
+              task.status === 'transcribing' ||
+              task.status === 'translating') && (
+              <div className="h-1.5 w-full overflow-hidden rounded-full bg-muted">
+                <div
+                  className="h-full bg-blue-500 transition-all"
+                  style={{ width: `${task.progress.percent}%` }}
+                />
+              </div>
+            )}
+
           {task.error && <p className="text-sm text-red-500">{task.error}</p>}
 
+          {task.warning && (
+            <p className="whitespace-pre-line text-sm text-yellow-600">
+              {task.warning}
+            </p>
+          )}
+
           {task.outputPath && (
             <p className="truncate text-xs text-muted-foreground">
               Audio: {task.outputPath}
@@ -79,12 +79,35 @@ function extractionReducer(
         ),
       }
 
+    case 'TASK_MEDIA_INFO':
+      return {
+        ...state,
+        tasks: state.tasks.map((task) =>
+          task.id === action.taskId
+            ? { ...task, mediaDuration: action.mediaDuration }
+            : task,
+        ),
+      }
+
+    case 'TASK_PROGRESS':
+      return {
+        ...state,
+        tasks: state.tasks.map((task) =>
+          task.id === action.taskId
+            ? {
+                ...task,
+                progress: { stage: action.stage, percent: action.percent },
+              }
+            : task,
+        ),
+      }
+
     case 'TASK_TRANSCRIBING':
       return {
         ...state,
         tasks: state.tasks.map((task) =>
           task.id === action.taskId
-            ? { ...task, status: 'transcribing' as const }
+            ? { ...task, status: 'transcribing' as const, progress: undefined }
             : task,
         ),
       }
@@ -94,7 +117,17 @@ function extractionReducer(
         ...state,
         tasks: state.tasks.map((task) =>
           task.id === action.taskId
-            ? { ...task, status: 'translating' as const }
+            ? {
+                ...task,
+                status: 'translating' as const,
+                progress: undefined,
+                languageStatus: action.targetLanguage
+                  ? {
+                      ...task.languageStatus,
+                      [action.targetLanguage]: 'translating' as const,
+                    }
+                  : task.languageStatus,
+              }
             : task,
         ),
       }
@@ -130,6 +163,37 @@ function extractionReducer(
         ),
       }
 
+    case 'TRANSLATION_LANGUAGE_COMPLETE':
+      return {
+        ...state,
+        tasks: state.tasks.map((task) =>
+          task.id === action.taskId
+            ? {
+                ...task,
+                languageStatus: {
+                  ...task.languageStatus,
+                  [action.targetLanguage]: action.status,
+                },
+              }
+            : task,
+        ),
+      }
+
+    case 'TRANSLATION_FALLBACK':
+      return {
+        ...state,
+        tasks: state.tasks.map((task) =>
+          task.id === action.taskId
+            ? {
+                ...task,
+                warning: task.warning
+                  ? `${task.warning}\n${action.warning}`
+                  : action.warning,
+              }
+            : task,
+        ),
+      }
+
     case 'TRANSLATION_COMPLETE':
       return {
         ...state,
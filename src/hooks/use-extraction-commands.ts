@@ -1,6 +1,12 @@
 import { invoke } from '@tauri-apps/api/core'
 import { open } from '@tauri-apps/plugin-dialog'
-import type { ExtractionTask, LogEntry } from '@/types/extraction'
+import type {
+  ExtractionTask,
+  LogEntry,
+  MediaInfo,
+  PendingJob,
+  VideoOptions,
+} from '@/types/extraction'
 import { env } from '@/env'
 
 export function useExtractionCommands() {
@@ -55,15 +61,17 @@ export function useExtractionCommands() {
   /**
    * Starts the extraction, transcription, and translation pipeline.
    * This will:
-   * 1. Extract audio from video files to WAV format
-   * 2. Upload audio to AssemblyAI for transcription
-   * 3. Translate SRT to target language
+   * 1. Extract audio from video files to WAV format (or an embedded text
+   *    subtitle stream, depending on `options.subtitleSource`)
+   * 2. Upload audio to AssemblyAI for transcription (skipped for embedded subtitles)
+   * 3. Translate SRT to each target language
    * 4. Generate final SRT subtitle files
    */
   const startExtraction = async (
     tasks: Array<ExtractionTask>,
     outputFolder: string,
-    targetLanguage: string = 'Chinese Simplified',
+    targetLanguage: string | Array<string> = 'Chinese Simplified',
+    options?: VideoOptions,
   ): Promise<void> => {
     try {
       await invoke('extract_audio_batch', {
@@ -71,10 +79,14 @@ export function useExtractionCommands() {
           id: task.id,
           filePath: task.filePath,
           targetLanguage,
+          audioStreamIndex: task.audioStreamIndex,
+          audioLanguage: task.audioLanguage,
+          subtitleStreamIndex: task.subtitleStreamIndex,
         })),
         outputFolder,
         backendUrl: env.VITE_BACKEND_URL,
         targetLanguage,
+        options,
       })
     } catch (error) {
       console.error(
@@ -94,6 +106,47 @@ export function useExtractionCommands() {
     }
   }
 
+  const getPendingJobs = async (): Promise<Array<PendingJob>> => {
+    try {
+      return await invoke<Array<PendingJob>>('get_pending_jobs')
+    } catch (error) {
+      console.error('Failed to get pending jobs:', error)
+      throw error
+    }
+  }
+
+  /**
+   * Resumes jobs left unfinished by a previous run or failed. Jobs whose audio was
+   * already uploaded re-attach to their backend transcription job.
+   */
+  const resumePendingJobs = async (): Promise<void> => {
+    try {
+      await invoke('resume_pending_jobs')
+    } catch (error) {
+      console.error('Failed to resume pending jobs:', error)
+      throw error
+    }
+  }
+
+  /** Discards a pending or failed job and the temp files it kept */
+  const dismissPendingJob = async (taskId: string): Promise<void> => {
+    try {
+      await invoke('dismiss_pending_job', { taskId })
+    } catch (error) {
+      console.error('Failed to dismiss pending job:', error)
+      throw error
+    }
+  }
+
+  const probeMedia = async (filePath: string): Promise<MediaInfo> => {
+    try {
+      return await invoke<MediaInfo>('probe_media', { filePath })
+    } catch (error) {
+      console.error('Failed to probe media:', error)
+      throw error
+    }
+  }
+
   const getTaskLogs = async (taskId: string): Promise<Array<LogEntry>> => {
     try {
       const logs = await invoke<Array<LogEntry>>('get_task_logs', { taskId })
@@ -119,6 +172,10 @@ export function useExtractionCommands() {
     selectOutputFolder,
     startExtraction,
     cancelExtraction,
+    getPendingJobs,
+    resumePendingJobs,
+    dismissPendingJob,
+    probeMedia,
     getTaskLogs,
     getLogFolder,
   }
@@ -2,14 +2,20 @@ import { useEffect, useRef } from 'react'
 import { listen } from '@tauri-apps/api/event'
 import type { UnlistenFn } from '@tauri-apps/api/event'
 import type {
+  TaskCancelledEvent,
   TaskCompleteEvent,
   TaskErrorEvent,
   TaskLogEvent,
+  TaskMediaInfoEvent,
+  TaskProgressEvent,
   TaskStartedEvent,
   TranscriptionCompleteEvent,
   TranscriptionPollingEvent,
   TranscriptionStartedEvent,
   TranslationCompleteEvent,
+  TranslationFallbackEvent,
+  TranslationLanguageCompleteEvent,
+  TranslationProgressEvent,
   TranslationStartedEvent,
 } from '@/types/extraction'
 import { useExtraction } from '@/context/extraction-context'
@@ -39,6 +45,25 @@ export function useExtractionEvents() {
           })
         }),
 
+        // Listen for media info probed before extraction
+        listen<TaskMediaInfoEvent>('task:media-info', (event) => {
+          dispatchRef.current({
+            type: 'TASK_MEDIA_INFO',
+            taskId: event.payload.taskId,
+            mediaDuration: event.payload.mediaInfo.durationSecs,
+          })
+        }),
+
+        // Listen for throttled progress events (e.g. ffmpeg extraction)
+        listen<TaskProgressEvent>('task:progress', (event) => {
+          dispatchRef.current({
+            type: 'TASK_PROGRESS',
+            taskId: event.payload.taskId,
+            stage: event.payload.stage,
+            percent: event.payload.percent,
+          })
+        }),
+
         // Listen for task completed events
         listen<TaskCompleteEvent>('task:completed', (event) => {
           dispatchRef.current({
@@ -57,6 +82,15 @@ export function useExtractionEvents() {
           })
         }),
 
+        // Listen for task cancelled events (temp files already cleaned up by the backend)
+        listen<TaskCancelledEvent>('task:cancelled', (event) => {
+          dispatchRef.current({
+            type: 'TASK_FAILED',
+            taskId: event.payload.taskId,
+            error: 'Cancelled by user',
+          })
+        }),
+
         // Listen for batch complete event
         listen('batch:complete', () => {
           dispatchRef.current({ type: 'STOP_PROCESSING' })
@@ -110,6 +144,46 @@ export function useExtractionEvents() {
           dispatchRef.current({
             type: 'TASK_TRANSLATING',
             taskId: event.payload.taskId,
+            targetLanguage: event.payload.targetLanguage,
+          })
+        }),
+
+        // Listen for chunked translation progress
+        listen<TranslationProgressEvent>('translation:progress', (event) => {
+          dispatchRef.current({
+            type: 'TASK_PROGRESS',
+            taskId: event.payload.taskId,
+            stage: 'translating',
+            percent:
+              (event.payload.completedChunks / event.payload.totalChunks) *
+              100,
+          })
+        }),
+
+        // Listen for per-language translation complete events
+        listen<TranslationLanguageCompleteEvent>(
+          'translation:language-complete',
+          (event) => {
+            dispatchRef.current({
+              type: 'TRANSLATION_LANGUAGE_COMPLETE',
+              taskId: event.payload.taskId,
+              targetLanguage: event.payload.targetLanguage,
+              status: event.payload.status,
+            })
+          },
+        ),
+
+        // Listen for languages that could not be fully translated
+        listen<TranslationFallbackEvent>('translation:fallback', (event) => {
+          const { targetLanguage, failedChunks, totalChunks, error } =
+            event.payload
+          dispatchRef.current({
+            type: 'TRANSLATION_FALLBACK',
+            taskId: event.payload.taskId,
+            warning:
+              event.payload.status === 'partial'
+                ? `${targetLanguage}: ${failedChunks} of ${totalChunks} chunks kept their original text (${error})`
+                : `${targetLanguage}: not translated, original subtitles written instead (${error})`,
           })
         }),
 
@@ -1,5 +1,6 @@
 import { invoke } from '@tauri-apps/api/core'
 import { open } from '@tauri-apps/plugin-dialog'
+import type { OutputOptions, TranslationOptions } from '@/types/extraction'
 import { env } from '@/env'
 
 export function useSrtTranslationCommands() {
@@ -8,8 +9,8 @@ export function useSrtTranslationCommands() {
       multiple: true,
       filters: [
         {
-          name: 'SRT Subtitles',
-          extensions: ['srt'],
+          name: 'Subtitles',
+          extensions: ['srt', 'ass', 'ssa'],
         },
       ],
     })
@@ -21,7 +22,10 @@ export function useSrtTranslationCommands() {
   const startTranslation = async (
     tasks: Array<{ taskId: string; filePath: string }>,
     outputFolder: string,
-    targetLanguage: string,
+    /** One language, or several to translate each file into */
+    targetLanguage: string | Array<string>,
+    /** Formats default to the input's own (SRT, or ASS for .ass/.ssa input) */
+    options?: OutputOptions & TranslationOptions,
   ) => {
     console.log('[useSrtTranslationCommands] Starting translation with:', {
       tasks,
@@ -39,6 +43,7 @@ export function useSrtTranslationCommands() {
         outputFolder,
         targetLanguage,
         backendUrl: env.VITE_BACKEND_URL,
+        options,
       })
       console.log(
         '[useSrtTranslationCommands] Translation started successfully',
@@ -28,12 +28,197 @@ export interface ExtractionTask {
   outputPath?: string
   transcriptPath?: string
   targetLanguage?: string
+  /** Per-language translation status when translating into several languages */
+  languageStatus?: Record<string, 'translating' | TranslationStatus>
+  /** Absolute index of the audio stream to extract (from probeMedia) */
+  audioStreamIndex?: number
+  /** Language tag of the audio stream to extract, e.g. 'jpn' */
+  audioLanguage?: string
+  /** Absolute index of an embedded subtitle stream to use instead of transcribing */
+  subtitleStreamIndex?: number
   error?: string
+  /** Set when a language was only partially translated or fell back to the original */
+  warning?: string
   startTime?: number
   endTime?: number
+  /** Media duration in seconds, as reported by ffprobe */
+  mediaDuration?: number
+  /** Progress of the current stage, as reported by `task:progress` */
+  progress?: { stage: string; percent: number }
   logs: Array<LogEntry>
 }
 
+/** Where the original-language SRT for a video comes from */
+export type SubtitleSource = 'transcribe' | 'embedded' | 'auto'
+
+export type SubtitleFormat = 'srt' | 'vtt' | 'ass'
+
+export type BilingualOrder = 'translationFirst' | 'originalFirst'
+
+/** Write both languages in each cue instead of the translation only */
+export interface BilingualOptions {
+  order?: BilingualOrder
+  /** Render the lower line smaller and in italics (VTT and ASS only) */
+  styleSecondary?: boolean
+}
+
+/** Which subtitle files to write for each translation */
+export interface OutputOptions {
+  /** Defaults to the input's own format (SRT for videos) */
+  outputFormats?: Array<SubtitleFormat>
+  bilingual?: BilingualOptions
+  /**
+   * Write `_partial`/`_untranslated` files when translation fails instead of
+   * failing the task (default true)
+   */
+  allowFallback?: boolean
+}
+
+/** OpenAI-compatible chat completions endpoint, e.g. a local llama.cpp or Ollama server */
+export interface OpenAiSettings {
+  /** API base URL including the version, e.g. 'http://localhost:11434/v1' */
+  baseUrl: string
+  apiKey?: string
+  model: string
+}
+
+/** LibreTranslate instance */
+export interface LibreTranslateSettings {
+  url: string
+  apiKey?: string
+}
+
+/** Which service translates the subtitles */
+export type TranslationProviderConfig =
+  | { type: 'server' }
+  | ({ type: 'openAiCompatible' } & OpenAiSettings)
+  | ({ type: 'libreTranslate' } & LibreTranslateSettings)
+
+/** Hints passed to the translation provider; unset fields use its defaults */
+export interface TranslationOptions {
+  /** Defaults to the translation server */
+  translationProvider?: TranslationProviderConfig
+  /** Language of the original subtitles; defaults to the language detected during transcription */
+  sourceLanguage?: string
+  /** Regional variant of the target language, e.g. 'BR' */
+  country?: string
+  /** Model on the translation server */
+  model?: string
+  /** Project whose glossary is sent with the requests and checked afterwards */
+  glossaryProject?: string
+}
+
+/** One required translation of a term */
+export interface GlossaryEntry {
+  source: string
+  target: string
+  /** Target language the entry applies to; omitted means every language */
+  language?: string
+}
+
+/** Terminology of one project, imported from CSV/TSV */
+export interface Glossary {
+  project: string
+  entries: Array<GlossaryEntry>
+  updatedAt: string
+}
+
+/** User-supplied whisper.cpp installation for offline transcription */
+export interface WhisperCppSettings {
+  /** Path to the `whisper-cli` executable */
+  binaryPath: string
+  /** Path to a ggml model file */
+  modelPath: string
+  threads?: number
+  /** Spoken language, e.g. 'ja'; detected automatically if omitted */
+  language?: string
+}
+
+/** Where the audio is transcribed; audio never leaves the machine with whisper.cpp */
+export type TranscriptionProviderConfig =
+  | { type: 'backend' }
+  | ({ type: 'whisperCpp' } & WhisperCppSettings)
+
+/** Codec of the audio extracted for transcription; whisper.cpp always uses WAV */
+export type AudioCodec =
+  | { type: 'wav' }
+  | { type: 'flac' }
+  | { type: 'opus'; bitrateKbps?: number }
+
+/** Per-batch options for the video pipeline */
+export interface VideoOptions extends OutputOptions, TranslationOptions {
+  preferredAudioLanguage?: string
+  subtitleSource?: SubtitleSource
+  /** Defaults to the transcription backend */
+  transcriptionProvider?: TranscriptionProviderConfig
+  /** Defaults to WAV; FLAC and Opus make much smaller uploads */
+  audioCodec?: AudioCodec
+  preferredSubtitleLanguage?: string
+  /**
+   * Write a copy of the video with the translated SRT muxed in as a soft subtitle
+   * (with this or `hardsub`, an SRT is written even if `outputFormats` leaves it out)
+   */
+  muxSubtitles?: boolean
+  /** Also mux the original-language SRT as a second subtitle track */
+  muxIncludeOriginal?: boolean
+  /**
+   * Burn the translation into the picture; omit to skip
+   * (with `ass` in `outputFormats`, the ASS file is burned with its own styles)
+   */
+  hardsub?: HardsubStyle
+}
+
+export type SubtitlePosition = 'bottom' | 'middle' | 'top'
+
+/** Styling for burned-in subtitles; unset fields use backend defaults */
+export interface HardsubStyle {
+  fontName?: string
+  fontSize?: number
+  outline?: number
+  position?: SubtitlePosition
+  marginV?: number
+}
+
+export type JobStage =
+  | 'queued'
+  | 'extracting'
+  | 'uploading'
+  | 'transcribing'
+  | 'translating'
+  | 'exporting'
+
+/** Video job persisted by the backend, left unfinished by a previous run or failed */
+export interface PendingJob {
+  taskId: string
+  filePath: string
+  outputFolder: string
+  backendUrl: string
+  targetLanguages: Array<string>
+  options: VideoOptions
+  stage: JobStage
+  audioPath?: string
+  originalSrtPath?: string
+  /** Language detected by the transcription backend */
+  detectedLanguage?: string
+  /** Translated SRT per target language, for languages already finished */
+  translatedSrtPaths?: Record<string, string>
+  /** Status of each language in `translatedSrtPaths` */
+  translationStatuses?: Record<string, TranslationStatus>
+  jobId?: string
+  /** Unfinished resumable uploads by audio path, continued when the job resumes */
+  uploads?: Record<string, PendingUpload>
+  /** Error of the last run; failed jobs are kept until resumed or dismissed */
+  error?: string
+  updatedAt: string
+}
+
+/** Resumable upload of an audio file, with the bytes the backend has confirmed */
+export interface PendingUpload {
+  url: string
+  offset: number
+  size: number
+}
+
 export interface ExtractionState {
   tasks: Array<ExtractionTask>
   outputFolder: string | null
@@ -52,6 +237,46 @@ export interface TaskErrorEvent {
   error: string
 }
 
+export interface AudioStreamInfo {
+  index: number
+  codec: string
+  language?: string
+  title?: string
+  channels: number
+  sampleRate: number
+  isDefault: boolean
+}
+
+export interface SubtitleStreamInfo {
+  index: number
+  codec: string
+  language?: string
+  title?: string
+  isDefault: boolean
+}
+
+export interface MediaInfo {
+  durationSecs?: number
+  container: string
+  audioStreams: Array<AudioStreamInfo>
+  subtitleStreams: Array<SubtitleStreamInfo>
+}
+
+export interface TaskProgressEvent {
+  taskId: string
+  stage: string
+  percent: number
+}
+
+export interface TaskMediaInfoEvent {
+  taskId: string
+  mediaInfo: MediaInfo
+}
+
+export interface TaskCancelledEvent {
+  taskId: string
+}
+
 export interface TaskStartedEvent {
   taskId: string
 }
@@ -78,16 +303,65 @@ export interface TranscriptionCompleteEvent {
   audioPath: string
   /** Path to the final SRT transcript file */
   transcriptPath: string
+  /** Language detected by the backend, if it reported one */
+  languageCode?: string
 }
 
 export interface TranslationStartedEvent {
   taskId: string
+  originalSrtPath: string
+  targetLanguage: string
+}
+
+/** Emitted as each chunk of a long file is translated */
+export interface TranslationProgressEvent {
+  taskId: string
+  targetLanguage: string
+  completedChunks: number
+  totalChunks: number
 }
 
+/** How much of a language's output is actually translated */
+export type TranslationStatus = 'translated' | 'partial' | 'fallback'
+
+/** Emitted once per target language as it finishes */
+export interface TranslationLanguageCompleteEvent {
+  taskId: string
+  targetLanguage: string
+  status: TranslationStatus
+  /** Every subtitle file written for this language, one per output format */
+  outputPaths: Array<string>
+  /** CSV of cues missing a glossary term, if any were found */
+  qaReportPath?: string
+}
+
+/** Emitted when some or all chunks of a language could not be translated */
+export interface TranslationFallbackEvent {
+  taskId: string
+  targetLanguage: string
+  status: Exclude<TranslationStatus, 'translated'>
+  failedChunks: number
+  totalChunks: number
+  error: string
+  outputPaths: Array<string>
+}
+
+/** Emitted once every target language is done */
 export interface TranslationCompleteEvent {
   taskId: string
+  /** Combined status of all target languages */
+  status: TranslationStatus
   /** Path to the final translated SRT file */
   translatedPath: string
+  /** Every subtitle file written, for all target languages */
+  outputPaths: Array<string>
+}
+
+/** Payload of `mux:complete` and `hardsub:complete` */
+export interface ExportCompleteEvent {
+  taskId: string
+  /** Path to the exported copy of the video */
+  outputPath: string
 }
 
 export type ExtractionAction =
@@ -101,8 +375,10 @@ export type ExtractionAction =
   | { type: 'START_PROCESSING' }
   | { type: 'STOP_PROCESSING' }
   | { type: 'TASK_STARTED'; taskId: string }
+  | { type: 'TASK_MEDIA_INFO'; taskId: string; mediaDuration?: number }
+  | { type: 'TASK_PROGRESS'; taskId: string; stage: string; percent: number }
   | { type: 'TASK_TRANSCRIBING'; taskId: string }
-  | { type: 'TASK_TRANSLATING'; taskId: string }
+  | { type: 'TASK_TRANSLATING'; taskId: string; targetLanguage?: string }
   | { type: 'TASK_COMPLETED'; taskId: string; outputPath: string }
   | {
       type: 'TASK_TRANSCRIPTION_COMPLETE'
@@ -110,6 +386,17 @@ export type ExtractionAction =
       audioPath: string
       transcriptPath: string
     }
+  | {
+      type: 'TRANSLATION_LANGUAGE_COMPLETE'
+      taskId: string
+      targetLanguage: string
+      status: TranslationStatus
+    }
+  | {
+      type: 'TRANSLATION_FALLBACK'
+      taskId: string
+      warning: string
+    }
   | {
       type: 'TRANSLATION_COMPLETE'
       taskId: string